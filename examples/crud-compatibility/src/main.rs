@@ -1,4 +1,5 @@
 use schema_registry::api::SchemaRegistryAPI;
+use schema_registry::client::config::SchemaRegistryConfig;
 use schema_registry::client::SchemaRegistryClient;
 use schema_registry::types::{
     CompatibilityLevel, SchemaType, SubjectConfig, UnregisteredSchema, Version,
@@ -57,7 +58,11 @@ const NORMALIZE: bool = false;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    let client = SchemaRegistryClient::from_url("http://localhost:8081")?;
+    // permanent deletes are used to cleanup the subject, so they must be explicitly allowed
+    let config = SchemaRegistryConfig::new()
+        .url("http://localhost:8081")
+        .allow_permanent_delete(true);
+    let client = SchemaRegistryClient::from_conf(config)?;
 
     // cleanup subject, gotta delete it twice because the first time it will be soft deleted
     client.delete_subject(SUBJECT, false).await.ok();
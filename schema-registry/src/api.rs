@@ -1,9 +1,29 @@
+use crate::backup::{
+    self, ImportOutcome, ImportReport, ImportedVersion, RegistrySnapshot, SnapshotDiff,
+    SnapshotSetting, SnapshotSubject, SnapshotVersion,
+};
 use crate::error::SchemaRegistryError;
 use crate::types::{
-    ClusterConfig, ExporterConfig, ExporterStatus, Mode, Schema, SchemaType, StringSchema, Subject,
-    SubjectConfig, SubjectVersion, UnregisteredSchema, Version,
+    ClusterConfig, ClusterId, CompatibilityLevel, CompatibilityResult, ContractPolicy,
+    ExporterConfig, ExporterStatus, Mode, Reference, RegistrationOutcome, RegistryFlavor,
+    ResolvedSchema, Schema, SchemaType, ServerVersion, StringSchema, Subject, SubjectConfig,
+    SubjectVersion, UnregisteredSchema, Version,
 };
-use std::collections::HashMap;
+use crate::wire;
+use futures::{StreamExt, TryStreamExt};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::time::Duration;
+
+/// Encodes Avro values into framed payloads, see [`SchemaRegistryAPI::avro_serializer`]
+#[cfg(feature = "avro")]
+pub type AvroSerializer =
+    Box<dyn Fn(&apache_avro::types::Value) -> Result<Vec<u8>, SchemaRegistryError> + Send + Sync>;
+
+/// Interval between reads while waiting for a write to become visible
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How many requests the methods walking many subjects or versions keep in flight at once
+const EXPORT_CONCURRENCY: usize = 8;
 
 /// The Schema Registry API trait
 ///
@@ -19,6 +39,15 @@ pub trait SchemaRegistryAPI: Send + Sync {
         schema: &UnregisteredSchema,
     ) -> Result<bool, SchemaRegistryError>;
 
+    /// Checks if a schema is compatible with the provided subject version, including the
+    /// registry's explanation when it is not
+    async fn is_compatible_verbose(
+        &self,
+        subject: &str,
+        version: Version,
+        schema: &UnregisteredSchema,
+    ) -> Result<CompatibilityResult, SchemaRegistryError>;
+
     /// Checks if a schema is compatible with all versions of the provided subject
     async fn is_fully_compatible(
         &self,
@@ -29,18 +58,40 @@ pub trait SchemaRegistryAPI: Send + Sync {
     /// Get the global configuration for the cluster
     async fn get_configuration(&self) -> Result<ClusterConfig, SchemaRegistryError>;
 
+    /// Get the global configuration for the cluster as the unparsed JSON the registry returns
+    async fn get_configuration_raw(&self) -> Result<serde_json::Value, SchemaRegistryError>;
+
     /// Update the global configuration for the cluster
     async fn update_configuration(
         &self,
         configuration: &ClusterConfig,
     ) -> Result<ClusterConfig, SchemaRegistryError>;
 
+    /// Delete the global configuration, resetting it to the registry defaults
+    ///
+    /// Returns the deleted configuration.
+    async fn delete_configuration(&self) -> Result<ClusterConfig, SchemaRegistryError>;
+
     /// Get the configuration for a specific subject
     async fn get_subject_configuration(
         &self,
         subject: &str,
     ) -> Result<SubjectConfig, SchemaRegistryError>;
 
+    /// Get the configuration for a specific subject, falling back to the global configuration
+    /// when `default_to_global` is set and the subject has none
+    async fn get_subject_configuration_with_default(
+        &self,
+        subject: &str,
+        default_to_global: bool,
+    ) -> Result<SubjectConfig, SchemaRegistryError>;
+
+    /// Get the configuration for a specific subject as the unparsed JSON the registry returns
+    async fn get_subject_configuration_raw(
+        &self,
+        subject: &str,
+    ) -> Result<serde_json::Value, SchemaRegistryError>;
+
     /// Update the configuration for a specific subject
     async fn update_subject_configuration(
         &self,
@@ -48,6 +99,14 @@ pub trait SchemaRegistryAPI: Send + Sync {
         configuration: &SubjectConfig,
     ) -> Result<SubjectConfig, SchemaRegistryError>;
 
+    /// Delete the configuration of a specific subject, making it inherit the global one
+    ///
+    /// Returns the deleted configuration.
+    async fn delete_subject_configuration(
+        &self,
+        subject: &str,
+    ) -> Result<SubjectConfig, SchemaRegistryError>;
+
     /// Get the list of exporters currently registered in the schema registry
     async fn get_exporters(&self) -> Result<Vec<String>, SchemaRegistryError>;
 
@@ -55,6 +114,34 @@ pub trait SchemaRegistryAPI: Send + Sync {
     /// and any custom contexts that were created in the registry.
     async fn get_contexts(&self) -> Result<Vec<String>, SchemaRegistryError>;
 
+    /// Check that the schema registry is reachable and answering
+    ///
+    /// Succeeds as soon as one of the configured URLs answers a lightweight `GET /config`
+    /// with a successful status.
+    async fn ping(&self) -> Result<(), SchemaRegistryError>;
+
+    /// Get the identity of the cluster the schema registry belongs to
+    async fn get_cluster_id(&self) -> Result<ClusterId, SchemaRegistryError>;
+
+    /// Get the version and commit of the schema registry build
+    async fn get_server_version(&self) -> Result<ServerVersion, SchemaRegistryError>;
+
+    /// Identify the schema registry implementation behind the configured URLs
+    ///
+    /// The implementation is guessed from the URL layout, the `Server` header and the
+    /// endpoints it supports, falling back to [`RegistryFlavor::Unknown`].
+    async fn detect_flavor(&self) -> Result<RegistryFlavor, SchemaRegistryError>;
+
+    /// Get all subjects registered within the provided context
+    ///
+    /// The context can be given with or without its leading dot (`.orders` or `orders`).
+    /// Returned subject names are stripped of their `:.context:` qualifier.
+    async fn get_subjects_in_context(
+        &self,
+        context: &str,
+        deleted: bool,
+    ) -> Result<Vec<String>, SchemaRegistryError>;
+
     /// Create a new exporter
     async fn create_exporter(&self, config: &ExporterConfig)
         -> Result<String, SchemaRegistryError>;
@@ -110,6 +197,14 @@ pub trait SchemaRegistryAPI: Send + Sync {
     /// Get subject resource mode
     async fn get_subject_resource_mode(&self, subject: &str) -> Result<Mode, SchemaRegistryError>;
 
+    /// Get subject resource mode, falling back to the global resource mode when
+    /// `default_to_global` is set and the subject has none
+    async fn get_subject_resource_mode_with_default(
+        &self,
+        subject: &str,
+        default_to_global: bool,
+    ) -> Result<Mode, SchemaRegistryError>;
+
     /// Set subject resource mode
     async fn update_subject_resource_mode(
         &self,
@@ -118,8 +213,11 @@ pub trait SchemaRegistryAPI: Send + Sync {
         force: bool,
     ) -> Result<Mode, SchemaRegistryError>;
 
-    /// Delete the subject resource mode
-    async fn delete_subject_mode(&self, subject: &str) -> Result<Mode, SchemaRegistryError>;
+    /// Delete the subject resource mode, returning the mode that was cleared
+    ///
+    /// Returns `None` when the subject had no mode of its own, so deleting it twice succeeds.
+    async fn delete_subject_mode(&self, subject: &str)
+        -> Result<Option<Mode>, SchemaRegistryError>;
 
     /// Get the schema identified by the provided id
     async fn get_schema_by_id(&self, id: u32) -> Result<Schema, SchemaRegistryError>;
@@ -139,9 +237,28 @@ pub trait SchemaRegistryAPI: Send + Sync {
     /// Get all subjects currently registered in the schema registry
     async fn get_subjects(&self, deleted: bool) -> Result<Vec<String>, SchemaRegistryError>;
 
+    /// Get the subjects matching the provided prefix within the provided context
+    ///
+    /// The context can be given with or without its leading dot (`.orders` or `orders`).
+    /// Subjects of a context are returned with their `:.context:` qualifier.
+    async fn get_subjects_filtered<'a>(
+        &self,
+        prefix: Option<&'a str>,
+        context: Option<&'a str>,
+        deleted: bool,
+    ) -> Result<Vec<String>, SchemaRegistryError>;
+
     /// Get the latest version of the schema for the provided subject
     async fn get_subject_versions(&self, subject: &str) -> Result<Vec<u32>, SchemaRegistryError>;
 
+    /// Get the versions of the provided subject, including soft deleted ones when `deleted`
+    /// is set
+    async fn get_subject_versions_with_deleted(
+        &self,
+        subject: &str,
+        deleted: bool,
+    ) -> Result<Vec<u32>, SchemaRegistryError>;
+
     /// Delete a subject, it's versions and associated compatibility level if it exists
     async fn delete_subject(
         &self,
@@ -149,6 +266,14 @@ pub trait SchemaRegistryAPI: Send + Sync {
         permanent: bool,
     ) -> Result<Vec<u32>, SchemaRegistryError>;
 
+    /// Delete a subject, forcing the deletion even if other schemas reference it
+    async fn delete_subject_forced(
+        &self,
+        subject: &str,
+        permanent: bool,
+        force: bool,
+    ) -> Result<Vec<u32>, SchemaRegistryError>;
+
     /// Get a specific version of the subject
     async fn get_subject_version(
         &self,
@@ -156,6 +281,14 @@ pub trait SchemaRegistryAPI: Send + Sync {
         version: Version,
     ) -> Result<Subject, SchemaRegistryError>;
 
+    /// Get a specific version of the subject, which may be soft deleted when `deleted` is set
+    async fn get_subject_version_opt(
+        &self,
+        subject: &str,
+        version: Version,
+        deleted: bool,
+    ) -> Result<Subject, SchemaRegistryError>;
+
     /// Get the raw schema for a specific version of the subject
     async fn get_subject_version_raw(
         &self,
@@ -171,6 +304,17 @@ pub trait SchemaRegistryAPI: Send + Sync {
         normalize: bool,
     ) -> Result<u32, SchemaRegistryError>;
 
+    /// Post a new schema to the schema registry, returning the registered subject version
+    ///
+    /// Recent registries answer with the version they assigned. With older ones, the version
+    /// is looked up with [`SchemaRegistryAPI::lookup_subject_schema`].
+    async fn register_subject_schema_full(
+        &self,
+        subject: &str,
+        schema: &UnregisteredSchema,
+        normalize: bool,
+    ) -> Result<Subject, SchemaRegistryError>;
+
     /// Lookup if a schema is registered under a subject
     async fn lookup_subject_schema(
         &self,
@@ -193,4 +337,1134 @@ pub trait SchemaRegistryAPI: Send + Sync {
         subject: &str,
         version: Version,
     ) -> Result<Vec<u32>, SchemaRegistryError>;
+
+    /// Delete every subject version registered with the provided schema id
+    ///
+    /// Returns the subject-version pairs that were removed. Unless `force` is set, the call
+    /// fails with [`SchemaRegistryError::LastSubjectVersion`] before deleting anything if
+    /// one of the versions is the only one left in its subject.
+    async fn delete_schema_everywhere(
+        &self,
+        id: u32,
+        permanent: bool,
+        force: bool,
+    ) -> Result<Vec<SubjectVersion>, SchemaRegistryError> {
+        let subject_versions = self.get_schema_subject_versions(id).await?;
+
+        if !force {
+            // every subject is checked before anything is deleted, so a refusal leaves the
+            // schema registered everywhere
+            let mut deleted_versions: BTreeMap<&str, Vec<u32>> = BTreeMap::new();
+
+            for subject_version in &subject_versions {
+                deleted_versions
+                    .entry(&subject_version.subject)
+                    .or_default()
+                    .push(subject_version.version);
+            }
+
+            for (subject, deleted) in deleted_versions {
+                let versions = self.get_subject_versions(subject).await?;
+
+                if versions.iter().all(|version| deleted.contains(version)) {
+                    return Err(SchemaRegistryError::LastSubjectVersion {
+                        subject: subject.to_owned(),
+                        version: deleted.into_iter().max().unwrap_or_default(),
+                    });
+                }
+            }
+        }
+
+        for subject_version in &subject_versions {
+            let version = Version::Number(subject_version.version);
+
+            // a version must be soft deleted before it can be permanently deleted
+            self.delete_subject_version(&subject_version.subject, version, false)
+                .await?;
+
+            if permanent {
+                self.delete_subject_version(&subject_version.subject, version, true)
+                    .await?;
+            }
+        }
+
+        Ok(subject_versions)
+    }
+
+    /// Get the schema identified by the provided id parsed into an Avro schema
+    ///
+    /// References are fetched transitively and parsed alongside the schema, so named types
+    /// they define resolve. Fails with [`SchemaRegistryError::InvalidSchemaType`] if the
+    /// schema isn't Avro.
+    #[cfg(feature = "avro")]
+    async fn get_avro_schema_by_id(
+        &self,
+        id: u32,
+    ) -> Result<apache_avro::Schema, SchemaRegistryError> {
+        let schema = self.get_schema_by_id(id).await?;
+
+        if schema.schema_type != SchemaType::Avro {
+            return Err(SchemaRegistryError::invalid_schema_type(format!(
+                "schema {} is {}, not AVRO",
+                id, schema.schema_type
+            )));
+        }
+
+        let invalid_schema = |e: apache_avro::Error| SchemaRegistryError::InvalidSchema {
+            message: e.to_string(),
+        };
+
+        let mut references = VecDeque::from(schema.references.clone().unwrap_or_default());
+
+        if references.is_empty() {
+            return apache_avro::Schema::parse_str(&schema.schema).map_err(invalid_schema);
+        }
+
+        let mut visited = HashSet::new();
+        let mut schemas = Vec::new();
+
+        while let Some(reference) = references.pop_front() {
+            if !visited.insert((reference.subject.clone(), reference.version)) {
+                continue;
+            }
+
+            let referenced = self
+                .get_subject_version(&reference.subject, Version::Number(reference.version))
+                .await?;
+
+            references.extend(referenced.references.unwrap_or_default());
+            schemas.push(referenced.schema.into_owned());
+        }
+
+        schemas.push(schema.schema.into_owned());
+
+        let input = schemas.iter().map(String::as_str).collect::<Vec<_>>();
+        let mut parsed = apache_avro::Schema::parse_list(&input).map_err(invalid_schema)?;
+
+        Ok(parsed.pop().expect("the fetched schema is parsed last"))
+    }
+
+    /// Register an Avro schema and return a serializer producing framed payloads for it
+    ///
+    /// The schema is parsed before being registered, and the returned closure encodes values
+    /// against it, prefixed with the wire format framing for its id. Schemas with references
+    /// are not supported.
+    #[cfg(feature = "avro")]
+    async fn avro_serializer(
+        &self,
+        subject: &str,
+        schema: &UnregisteredSchema,
+        normalize: bool,
+    ) -> Result<AvroSerializer, SchemaRegistryError> {
+        if schema
+            .references
+            .as_deref()
+            .is_some_and(|refs| !refs.is_empty())
+        {
+            return Err(SchemaRegistryError::InvalidSchema {
+                message: "schemas with references cannot be serialized".to_owned(),
+            });
+        }
+
+        let parsed = apache_avro::Schema::parse_str(&schema.schema).map_err(|e| {
+            SchemaRegistryError::InvalidSchema {
+                message: e.to_string(),
+            }
+        })?;
+
+        let id = self
+            .post_new_subject_version(subject, schema, normalize)
+            .await?;
+
+        Ok(Box::new(move |value| {
+            wire::serialize_with(id, value, |value| {
+                apache_avro::to_avro_datum(&parsed, value.clone()).map_err(Box::new)
+            })
+        }))
+    }
+
+    /// Suggest the changes that would make an Avro schema compatible with the latest version
+    /// of the subject
+    ///
+    /// Suggestions come from comparing the fields of both records and from the registry's
+    /// verbose incompatibility messages. A compatible schema yields no suggestions.
+    #[cfg(feature = "avro")]
+    async fn suggest_compatible_changes(
+        &self,
+        subject: &str,
+        schema: &UnregisteredSchema,
+    ) -> Result<Vec<String>, SchemaRegistryError> {
+        let result = self
+            .is_compatible_verbose(subject, Version::Latest, schema)
+            .await?;
+
+        if result.compatible {
+            return Ok(Vec::new());
+        }
+
+        let latest = self.get_subject_version(subject, Version::Latest).await?;
+
+        let mut suggestions = Vec::new();
+        let mut analyzed_fields = HashSet::new();
+
+        if let (Ok(apache_avro::Schema::Record(old)), Ok(apache_avro::Schema::Record(new))) = (
+            apache_avro::Schema::parse_str(&latest.schema),
+            apache_avro::Schema::parse_str(&schema.schema),
+        ) {
+            for field in old
+                .fields
+                .iter()
+                .filter(|f| !new.lookup.contains_key(&f.name))
+            {
+                if field.default.is_none() {
+                    analyzed_fields.insert(field.name.clone());
+                    suggestions.push(format!(
+                        "Keep field '{}', or register a version giving it a default value before removing it",
+                        field.name
+                    ));
+                }
+            }
+
+            for field in new
+                .fields
+                .iter()
+                .filter(|f| !old.lookup.contains_key(&f.name))
+            {
+                if field.default.is_none() {
+                    analyzed_fields.insert(field.name.clone());
+                    suggestions.push(format!("Add a default value to field '{}'", field.name));
+                }
+            }
+        }
+
+        for message in &result.messages {
+            let Some((error_type, info)) = parse_incompatibility(message) else {
+                continue;
+            };
+
+            if analyzed_fields.contains(info) {
+                continue;
+            }
+
+            let suggestion = match error_type {
+                "READER_FIELD_MISSING_DEFAULT_VALUE" => {
+                    format!("Add a default value to field '{}'", info)
+                }
+                "TYPE_MISMATCH" => format!(
+                    "Restore the previous type or use an Avro type promotion ({})",
+                    info
+                ),
+                "NAME_MISMATCH" => format!("Keep the name '{}' or declare it as an alias", info),
+                "MISSING_ENUM_SYMBOLS" => {
+                    format!("Keep the enum symbols {} or give the enum a default", info)
+                }
+                "MISSING_UNION_BRANCH" => format!("Keep the union branch {}", info),
+                _ => continue,
+            };
+
+            if !suggestions.contains(&suggestion) {
+                suggestions.push(suggestion);
+            }
+        }
+
+        Ok(suggestions)
+    }
+
+    /// Get a subject version along with every subject version it references, transitively
+    ///
+    /// Subjects are returned in dependency order, dependencies first and the requested subject
+    /// version last, so they can be registered elsewhere in sequence. Fails with
+    /// [`SchemaRegistryError::ReferenceCycle`] if the references loop back on themselves.
+    async fn reference_closure(
+        &self,
+        subject: &str,
+        version: Version,
+    ) -> Result<Vec<Subject>, SchemaRegistryError> {
+        let target = self.get_subject_version(subject, version).await?;
+
+        let mut visiting = HashSet::from([(target.subject.clone(), target.version)]);
+        let mut done = HashSet::new();
+        let mut stack = vec![(target, 0)];
+        let mut closure = Vec::new();
+
+        while let Some((current, next)) = stack.last_mut() {
+            let Some(reference) = current.references.iter().flatten().nth(*next).cloned() else {
+                let (current, _) = stack.pop().expect("the stack is not empty");
+                let key = (current.subject.clone(), current.version);
+
+                visiting.remove(&key);
+                done.insert(key);
+                closure.push(current);
+                continue;
+            };
+
+            *next += 1;
+
+            let key = (reference.subject, reference.version);
+
+            if done.contains(&key) {
+                continue;
+            }
+
+            if visiting.contains(&key) {
+                return Err(SchemaRegistryError::ReferenceCycle {
+                    subject: key.0,
+                    version: key.1,
+                });
+            }
+
+            let referenced = self
+                .get_subject_version(&key.0, Version::Number(key.1))
+                .await?;
+
+            visiting.insert(key);
+            stack.push((referenced, 0));
+        }
+
+        Ok(closure)
+    }
+
+    /// Get a schema along with every schema it references, transitively
+    ///
+    /// Referenced subject versions appear once in dependency order, dependencies first. Fails
+    /// with [`SchemaRegistryError::ReferenceCycle`] if the references loop back on themselves.
+    async fn get_schema_with_references(
+        &self,
+        id: u32,
+    ) -> Result<ResolvedSchema, SchemaRegistryError> {
+        let root = self.get_schema_by_id(id).await?;
+
+        let mut names: HashMap<(String, u32), String> = HashMap::new();
+        let mut done = HashSet::new();
+        let mut dependencies = Vec::new();
+
+        for reference in root.references.iter().flatten() {
+            if done.contains(&(reference.subject.clone(), reference.version)) {
+                continue;
+            }
+
+            let closure = self
+                .reference_closure(&reference.subject, Version::Number(reference.version))
+                .await?;
+
+            // References are named by their referrer, so the names come from the closure
+            let referrers = closure
+                .iter()
+                .flat_map(|subject| subject.references.iter().flatten());
+
+            for reference in root.references.iter().flatten().chain(referrers) {
+                names
+                    .entry((reference.subject.clone(), reference.version))
+                    .or_insert_with(|| reference.name.clone());
+            }
+
+            for subject in closure {
+                let key = (subject.subject.clone(), subject.version);
+
+                if !done.insert(key.clone()) {
+                    continue;
+                }
+
+                let reference = Reference {
+                    name: names.get(&key).cloned().unwrap_or_default(),
+                    subject: key.0,
+                    version: key.1,
+                };
+
+                dependencies.push((reference, Schema::from(subject)));
+            }
+        }
+
+        Ok(ResolvedSchema { root, dependencies })
+    }
+
+    /// Get every subject version that references the provided one, directly or transitively
+    ///
+    /// The reverse-reference graph is walked breadth first, so direct referrers come first.
+    /// Subject versions already visited are not walked again, which keeps reference cycles
+    /// from looping forever.
+    async fn deletion_impact(
+        &self,
+        subject: &str,
+        version: Version,
+    ) -> Result<Vec<SubjectVersion>, SchemaRegistryError> {
+        let mut impacted = Vec::new();
+        let mut visited_versions = HashSet::new();
+        let mut visited_ids = HashSet::new();
+        let mut pending = VecDeque::from([(subject.to_owned(), version)]);
+
+        if let Version::Number(number) = version {
+            visited_versions.insert(SubjectVersion {
+                subject: subject.to_owned(),
+                version: number,
+            });
+        }
+
+        while let Some((subject, version)) = pending.pop_front() {
+            let ids = self
+                .get_subject_version_references(&subject, version)
+                .await?;
+
+            for id in ids {
+                if !visited_ids.insert(id) {
+                    continue;
+                }
+
+                for referrer in self.get_schema_subject_versions(id).await? {
+                    if visited_versions.insert(referrer.clone()) {
+                        let next = (referrer.subject.clone(), Version::Number(referrer.version));
+                        pending.push_back(next);
+                        impacted.push(referrer);
+                    }
+                }
+            }
+        }
+
+        Ok(impacted)
+    }
+
+    /// Get the resource mode of every subject
+    ///
+    /// Subject modes are fetched concurrently. Subjects without a mode override report the
+    /// global resource mode.
+    async fn all_subject_modes(&self) -> Result<HashMap<String, Mode>, SchemaRegistryError> {
+        let subjects = self.get_subjects(false).await?;
+        let global = self.get_global_resource_mode().await?;
+
+        futures::stream::iter(subjects)
+            .map(|subject| async move {
+                match self.get_subject_resource_mode(&subject).await {
+                    Ok(mode) => Ok((subject, mode)),
+                    Err(e) if e.is_not_found() => Ok((subject, global)),
+                    Err(e) => Err(e),
+                }
+            })
+            .buffer_unordered(EXPORT_CONCURRENCY)
+            .try_collect()
+            .await
+    }
+
+    /// Checks if a schema is compatible with each of the provided subject versions
+    ///
+    /// The checks run concurrently and the schema is compatible only if every check passes.
+    async fn is_compatible_with_versions(
+        &self,
+        subject: &str,
+        versions: &[u32],
+        schema: &UnregisteredSchema,
+    ) -> Result<bool, SchemaRegistryError> {
+        let checks: Vec<bool> = futures::stream::iter(versions.iter().copied())
+            .map(|version| self.is_compatible(subject, Version::Number(version), schema))
+            .buffer_unordered(EXPORT_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        Ok(checks.into_iter().all(|compatible| compatible))
+    }
+
+    /// Check that every version of a subject is compatible with the version before it
+    ///
+    /// Returns a `(previous, next, compatible)` triple for each pair of adjacent versions,
+    /// checked against the compatibility level of the subject.
+    async fn validate_version_chain(
+        &self,
+        subject: &str,
+    ) -> Result<Vec<(u32, u32, bool)>, SchemaRegistryError> {
+        let mut versions = self.get_subject_versions(subject).await?;
+        versions.sort_unstable();
+
+        let pairs: Vec<(u32, u32)> = versions.windows(2).map(|pair| (pair[0], pair[1])).collect();
+
+        futures::stream::iter(pairs)
+            .map(|(previous, next)| async move {
+                let registered = self
+                    .get_subject_version(subject, Version::Number(next))
+                    .await?;
+                let mut schema = UnregisteredSchema::schema(registered.schema)
+                    .schema_type(registered.schema_type);
+
+                if let Some(references) = registered.references {
+                    schema = schema.references(references);
+                }
+
+                let compatible = self
+                    .is_compatible(subject, Version::Number(previous), &schema)
+                    .await?;
+
+                Ok((previous, next, compatible))
+            })
+            .buffered(EXPORT_CONCURRENCY)
+            .try_collect()
+            .await
+    }
+
+    /// Resolve a version to its concrete number
+    ///
+    /// Numbered versions are returned as is, [`Version::Latest`] is resolved by fetching the
+    /// latest version of the subject.
+    async fn resolve_version(
+        &self,
+        subject: &str,
+        version: Version,
+    ) -> Result<u32, SchemaRegistryError> {
+        match version {
+            Version::Number(version) => Ok(version),
+            Version::Latest => Ok(self
+                .get_subject_version(subject, Version::Latest)
+                .await?
+                .version),
+        }
+    }
+
+    /// List every `(subject, version, schema id)` triple of the registry
+    ///
+    /// Triples are ordered by subject, then version.
+    async fn full_index(&self) -> Result<Vec<(String, u32, u32)>, SchemaRegistryError> {
+        let subjects = self.get_subjects(false).await?;
+
+        let versions: Vec<(String, Vec<u32>)> = futures::stream::iter(subjects)
+            .map(|subject| async move {
+                let versions = self.get_subject_versions(&subject).await?;
+
+                Ok::<_, SchemaRegistryError>((subject, versions))
+            })
+            .buffer_unordered(EXPORT_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        let pairs: Vec<(String, u32)> = versions
+            .into_iter()
+            .flat_map(|(subject, versions)| {
+                versions
+                    .into_iter()
+                    .map(move |version| (subject.clone(), version))
+            })
+            .collect();
+
+        let mut index: Vec<_> = futures::stream::iter(pairs)
+            .map(|(subject, version)| async move {
+                let registered = self
+                    .get_subject_version(&subject, Version::Number(version))
+                    .await?;
+
+                Ok::<_, SchemaRegistryError>((subject, version, registered.id))
+            })
+            .buffer_unordered(EXPORT_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        index.sort();
+
+        Ok(index)
+    }
+
+    /// Frame a payload in the wire format with the schema id of a subject version
+    ///
+    /// See [`crate::wire::encode_wire_format`] for the framing.
+    async fn frame_for_subject_version(
+        &self,
+        subject: &str,
+        version: Version,
+        payload: &[u8],
+    ) -> Result<Vec<u8>, SchemaRegistryError> {
+        let registered = self.get_subject_version(subject, version).await?;
+
+        Ok(wire::encode_wire_format(registered.id, payload))
+    }
+
+    /// Get the subject versions registered since the provided watermark
+    ///
+    /// The watermark maps subject names to the last version already seen. Subjects missing
+    /// from it report all their versions. Results are ordered by subject, then version.
+    async fn changes_since(
+        &self,
+        watermark: HashMap<String, u32>,
+    ) -> Result<Vec<SubjectVersion>, SchemaRegistryError> {
+        let subjects = self.get_subjects(false).await?;
+        let watermark = &watermark;
+
+        let versions: Vec<Vec<SubjectVersion>> = futures::stream::iter(subjects)
+            .map(|subject| async move {
+                let seen = watermark.get(&subject).copied().unwrap_or_default();
+                let versions = self.get_subject_versions(&subject).await?;
+
+                Ok::<_, SchemaRegistryError>(
+                    versions
+                        .into_iter()
+                        .filter(|version| *version > seen)
+                        .map(|version| SubjectVersion {
+                            subject: subject.clone(),
+                            version,
+                        })
+                        .collect(),
+                )
+            })
+            .buffer_unordered(EXPORT_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        let mut changes = versions.into_iter().flatten().collect::<Vec<_>>();
+        changes.sort_by(|a, b| a.subject.cmp(&b.subject).then(a.version.cmp(&b.version)));
+
+        Ok(changes)
+    }
+
+    /// Compare a schema with the form the registry normalizes it to
+    ///
+    /// Looks the schema up under the provided subject with normalization enabled and returns
+    /// the original text alongside the normalized text stored by the registry.
+    async fn normalize_compare(
+        &self,
+        subject: &str,
+        schema: &UnregisteredSchema,
+    ) -> Result<(String, String), SchemaRegistryError> {
+        let normalized = self.lookup_subject_schema(subject, schema, true).await?;
+
+        Ok((schema.schema.clone(), normalized.schema.into_owned()))
+    }
+
+    /// Find the id of a schema registered under any subject
+    ///
+    /// The registry has no subject-agnostic lookup endpoint, so this falls back to looking the
+    /// schema up under every subject in turn, stopping at the first match. Returns `None` if
+    /// the schema isn't registered anywhere.
+    async fn find_id_by_schema(
+        &self,
+        schema: &UnregisteredSchema,
+    ) -> Result<Option<u32>, SchemaRegistryError> {
+        for subject in self.get_subjects(false).await? {
+            match self.lookup_subject_schema(&subject, schema, false).await {
+                Ok(registered) => return Ok(Some(registered.id)),
+                Err(e) if e.is_not_found() => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Wait until a subject has at least `count` versions
+    ///
+    /// Polls [`SchemaRegistryAPI::get_subject_versions`] until enough versions are registered,
+    /// returning them, and fails with [`SchemaRegistryError::Timeout`] once `timeout` elapses.
+    /// A subject that does not exist yet is treated as having no versions.
+    async fn wait_for_versions(
+        &self,
+        subject: &str,
+        count: usize,
+        timeout: Duration,
+    ) -> Result<Vec<u32>, SchemaRegistryError> {
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        loop {
+            let versions = match self.get_subject_versions(subject).await {
+                Ok(versions) => versions,
+                Err(e) if e.is_not_found() => Vec::new(),
+                Err(e) => return Err(e),
+            };
+
+            if versions.len() >= count {
+                return Ok(versions);
+            }
+
+            if tokio::time::Instant::now() + POLL_INTERVAL >= deadline {
+                return Err(SchemaRegistryError::Timeout {
+                    message: format!(
+                        "subject '{}' had {} of {} versions after {:?}",
+                        subject,
+                        versions.len(),
+                        count,
+                        timeout
+                    ),
+                });
+            }
+
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Update only the fields of a subject configuration that are set in `patch`
+    ///
+    /// The current subject configuration is fetched and overlaid with the `Some` fields of the
+    /// patch before being written back, so unset fields keep their value. Returns the merged
+    /// configuration.
+    async fn patch_subject_configuration(
+        &self,
+        subject: &str,
+        patch: SubjectConfig,
+    ) -> Result<SubjectConfig, SchemaRegistryError> {
+        let current = match self.get_subject_configuration(subject).await {
+            Ok(config) => config,
+            Err(e) if e.is_not_found() => SubjectConfig::default(),
+            Err(e) => return Err(e),
+        };
+
+        let merged = current.overlay(patch);
+        self.update_subject_configuration(subject, &merged).await?;
+
+        Ok(merged)
+    }
+
+    /// Get the policy governing schema evolution of a subject
+    ///
+    /// Combines the compatibility level, compatibility group and metadata of the subject
+    /// configuration, falling back to the global configuration when the subject has none.
+    async fn resolve_contract_policy(
+        &self,
+        subject: &str,
+    ) -> Result<ContractPolicy, SchemaRegistryError> {
+        match self.get_subject_configuration(subject).await {
+            Ok(config) => Ok(ContractPolicy::from(config)),
+            Err(e) if e.is_not_found() => Ok(ContractPolicy::from(self.get_configuration().await?)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Capture the compatibility level of every subject
+    ///
+    /// Subjects relying on the global compatibility level are recorded as `None`, so
+    /// restoring the snapshot removes any level they were given since.
+    async fn snapshot_compatibilities(
+        &self,
+    ) -> Result<HashMap<String, Option<CompatibilityLevel>>, SchemaRegistryError> {
+        let subjects = self.get_subjects(false).await?;
+
+        futures::stream::iter(subjects)
+            .map(|subject| async move {
+                match self.get_subject_configuration(&subject).await {
+                    Ok(config) => Ok((subject, config.compatibility_level)),
+                    Err(e) if e.is_not_found() => Ok((subject, None)),
+                    Err(e) => Err(e),
+                }
+            })
+            .buffer_unordered(EXPORT_CONCURRENCY)
+            .try_collect()
+            .await
+    }
+
+    /// Apply the compatibility levels of a snapshot taken with
+    /// [`SchemaRegistryAPI::snapshot_compatibilities`]
+    ///
+    /// The subject configuration of subjects that relied on the global level is deleted.
+    async fn restore_compatibilities(
+        &self,
+        snapshot: &HashMap<String, Option<CompatibilityLevel>>,
+    ) -> Result<(), SchemaRegistryError> {
+        let levels: Vec<_> = snapshot
+            .iter()
+            .map(|(subject, level)| (subject.clone(), *level))
+            .collect();
+
+        futures::stream::iter(levels)
+            .map(|(subject, level)| async move {
+                match level {
+                    Some(level) => {
+                        let config = SubjectConfig::new().compatibility_level(level);
+
+                        self.update_subject_configuration(&subject, &config)
+                            .await
+                            .map(drop)
+                    }
+                    None => match self.delete_subject_configuration(&subject).await {
+                        Err(e) if !e.is_not_found() => Err(e),
+                        _ => Ok(()),
+                    },
+                }
+            })
+            .buffer_unordered(EXPORT_CONCURRENCY)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Take a copy of every subject and version, with their ids, configs and modes
+    ///
+    /// Soft deleted subjects and versions are included when `deleted` is set. The snapshot can
+    /// be serialized for a portable backup.
+    async fn export_all(&self, deleted: bool) -> Result<RegistrySnapshot, SchemaRegistryError> {
+        let config = match self.get_configuration().await {
+            Ok(config) => Some(config),
+            Err(e) if e.is_not_found() => None,
+            Err(e) => return Err(e),
+        };
+
+        let mode = match self.get_global_resource_mode().await {
+            Ok(mode) => Some(mode),
+            Err(e) if e.is_not_found() => None,
+            Err(e) => return Err(e),
+        };
+
+        let subjects = self.get_subjects(deleted).await?;
+
+        let subjects: Vec<(String, SnapshotSubject)> = futures::stream::iter(subjects)
+            .map(|subject| async move {
+                let config = match self.get_subject_configuration(&subject).await {
+                    Ok(config) => Some(config),
+                    Err(e) if e.is_not_found() => None,
+                    Err(e) => return Err(e),
+                };
+
+                let mode = match self.get_subject_resource_mode(&subject).await {
+                    Ok(mode) => Some(mode),
+                    Err(e) if e.is_not_found() => None,
+                    Err(e) => return Err(e),
+                };
+
+                let mut versions = BTreeMap::new();
+
+                for version in self
+                    .get_subject_versions_with_deleted(&subject, deleted)
+                    .await?
+                {
+                    let registered = self
+                        .get_subject_version_opt(&subject, Version::Number(version), deleted)
+                        .await?;
+
+                    let id = registered.id;
+                    versions.insert(
+                        version,
+                        SnapshotVersion {
+                            id,
+                            schema: Schema::from(registered),
+                        },
+                    );
+                }
+
+                Ok((
+                    subject,
+                    SnapshotSubject {
+                        versions,
+                        config,
+                        mode,
+                    },
+                ))
+            })
+            .buffer_unordered(EXPORT_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        Ok(RegistrySnapshot {
+            config,
+            mode,
+            subjects: subjects.into_iter().collect(),
+        })
+    }
+
+    /// Restore a snapshot taken with [`SchemaRegistryAPI::export_all`]
+    ///
+    /// The registry is put in [`Mode::Import`], forcefully as it may not be empty, and every
+    /// subject version is registered with its original id and version, referenced versions
+    /// first. Versions already in the registry are skipped. The global and subject configs are
+    /// restored next, then the global mode is set back to the one of the snapshot, or
+    /// [`Mode::ReadWrite`], and the subject modes are restored last.
+    ///
+    /// Only failing to enter [`Mode::Import`] aborts the restore. Any later failure is recorded
+    /// in the report, and the global mode is reset whatever happened.
+    async fn import_all(
+        &self,
+        snapshot: &RegistrySnapshot,
+    ) -> Result<ImportReport, SchemaRegistryError> {
+        self.update_global_resource_mode(Mode::Import, true).await?;
+
+        let mut report = ImportReport::default();
+
+        for (subject, version) in backup::import_order(snapshot) {
+            let snapshot_version = &snapshot.subjects[subject].versions[&version];
+
+            let outcome = match self
+                .get_subject_version_opt(subject, Version::Number(version), true)
+                .await
+            {
+                Ok(_) => ImportOutcome::Skipped,
+                Err(e) if e.is_not_found() => {
+                    let schema = &snapshot_version.schema;
+                    let unregistered = UnregisteredSchema::schema(schema.schema.clone())
+                        .schema_type(schema.schema_type.clone())
+                        .references(schema.references.iter().flatten().cloned())
+                        .with_id(snapshot_version.id)
+                        .with_version(version);
+
+                    match self
+                        .post_new_subject_version(subject, &unregistered, false)
+                        .await
+                    {
+                        Ok(_) => ImportOutcome::Imported,
+                        Err(e) => ImportOutcome::Failed {
+                            message: e.to_string(),
+                        },
+                    }
+                }
+                Err(e) => ImportOutcome::Failed {
+                    message: e.to_string(),
+                },
+            };
+
+            report.versions.push(ImportedVersion {
+                subject: subject.to_owned(),
+                version,
+                outcome,
+            });
+        }
+
+        if let Some(config) = &snapshot.config {
+            if let Err(e) = self.update_configuration(config).await {
+                report.record_failure(None, SnapshotSetting::Config, e);
+            }
+        }
+
+        for (subject, snapshot_subject) in &snapshot.subjects {
+            if let Some(config) = &snapshot_subject.config {
+                if let Err(e) = self.update_subject_configuration(subject, config).await {
+                    report.record_failure(Some(subject), SnapshotSetting::Config, e);
+                }
+            }
+        }
+
+        let mode = snapshot.mode.unwrap_or(Mode::ReadWrite);
+
+        if let Err(e) = self.update_global_resource_mode(mode, true).await {
+            report.record_failure(None, SnapshotSetting::Mode, e);
+        }
+
+        for (subject, snapshot_subject) in &snapshot.subjects {
+            if let Some(mode) = snapshot_subject.mode {
+                if let Err(e) = self.update_subject_resource_mode(subject, mode, true).await {
+                    report.record_failure(Some(subject), SnapshotSetting::Mode, e);
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Compare the live registry to a snapshot taken with [`SchemaRegistryAPI::export_all`]
+    ///
+    /// Subjects are reported as added or removed relative to the snapshot. Soft deleted
+    /// subjects and versions of the live registry are left out.
+    async fn diff_against_snapshot(
+        &self,
+        snapshot: &RegistrySnapshot,
+    ) -> Result<SnapshotDiff, SchemaRegistryError> {
+        let live = self.export_all(false).await?;
+        let mut diff = SnapshotDiff {
+            global_config_changed: live.config != snapshot.config,
+            ..SnapshotDiff::default()
+        };
+
+        for (subject, live_subject) in &live.subjects {
+            let Some(captured) = snapshot.subjects.get(subject) else {
+                diff.added.push(subject.clone());
+                continue;
+            };
+
+            if live_subject.versions != captured.versions {
+                diff.changed.push(subject.clone());
+            }
+
+            if live_subject.config != captured.config {
+                diff.config_changed.push(subject.clone());
+            }
+        }
+
+        diff.removed = snapshot
+            .subjects
+            .keys()
+            .filter(|subject| !live.subjects.contains_key(*subject))
+            .cloned()
+            .collect();
+
+        Ok(diff)
+    }
+
+    /// Compare the live registry to a bundle
+    ///
+    /// Deprecated: bundles were merged into [`RegistrySnapshot`], use
+    /// [`SchemaRegistryAPI::diff_against_snapshot`] instead. Not marked `#[deprecated]` as the
+    /// attribute would be copied onto the generated mock.
+    async fn diff_against_bundle(
+        &self,
+        bundle: &RegistrySnapshot,
+    ) -> Result<SnapshotDiff, SchemaRegistryError> {
+        self.diff_against_snapshot(bundle).await
+    }
+
+    /// Lookup if a schema is registered under a subject, normalizing it when the subject's
+    /// effective configuration does
+    ///
+    /// The subject configuration is used when set, falling back to the global configuration.
+    async fn lookup_respecting_config(
+        &self,
+        subject: &str,
+        schema: &UnregisteredSchema,
+    ) -> Result<Subject, SchemaRegistryError> {
+        let normalize = match self.get_subject_configuration(subject).await {
+            Ok(config) => config.normalize,
+            Err(e) if e.is_not_found() => self.get_configuration().await?.normalize,
+            Err(e) => return Err(e),
+        };
+
+        self.lookup_subject_schema(subject, schema, normalize.unwrap_or_default())
+            .await
+    }
+
+    /// Post a new schema and report whether it created a new version
+    ///
+    /// The registry answers a duplicate registration with the existing id, so `created` is
+    /// derived on a best-effort basis by comparing the subject versions before and after the
+    /// registration. A concurrent registration of the same schema may be reported as existing.
+    ///
+    /// The version is read back from the registry rather than inferred, so re-registering an
+    /// identical schema reports the version it is already registered under.
+    async fn register_subject_version_detailed(
+        &self,
+        subject: &str,
+        schema: &UnregisteredSchema,
+        normalize: bool,
+    ) -> Result<RegistrationOutcome, SchemaRegistryError> {
+        let existing_versions = match self.get_subject_versions(subject).await {
+            Ok(versions) => versions,
+            Err(e) if e.is_not_found() => Vec::new(),
+            Err(e) => return Err(e),
+        };
+
+        let id = self
+            .post_new_subject_version(subject, schema, normalize)
+            .await?;
+        let registered = self
+            .lookup_subject_schema(subject, schema, normalize)
+            .await?;
+
+        Ok(RegistrationOutcome {
+            id,
+            version: registered.version,
+            created: !existing_versions.contains(&registered.version),
+        })
+    }
+
+    /// Post a schema only when it is not already registered under the subject
+    ///
+    /// The schema is looked up first and only registered when the subject (40401) or the
+    /// schema (40403) is not found, so no write reaches the registry for a known schema.
+    /// Returns the id along with whether the schema was newly registered.
+    async fn register_if_absent(
+        &self,
+        subject: &str,
+        schema: &UnregisteredSchema,
+        normalize: bool,
+    ) -> Result<(u32, bool), SchemaRegistryError> {
+        match self.lookup_subject_schema(subject, schema, normalize).await {
+            Ok(registered) => Ok((registered.id, false)),
+            Err(e) if e.is_subject_not_found() || e.is_schema_not_found() => {
+                let id = self
+                    .post_new_subject_version(subject, schema, normalize)
+                    .await?;
+
+                Ok((id, true))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Post the key and value schemas of a topic
+    ///
+    /// The schemas are registered under the `{topic}-key` and `{topic}-value` subjects,
+    /// and the ids are returned in that order.
+    async fn register_topic_schemas(
+        &self,
+        topic: &str,
+        key_schema: &UnregisteredSchema,
+        value_schema: &UnregisteredSchema,
+        normalize: bool,
+    ) -> Result<(u32, u32), SchemaRegistryError> {
+        let key_subject = format!("{}-key", topic);
+        let value_subject = format!("{}-value", topic);
+
+        futures::try_join!(
+            self.post_new_subject_version(&key_subject, key_schema, normalize),
+            self.post_new_subject_version(&value_subject, value_schema, normalize),
+        )
+    }
+
+    /// Post many schemas, each under its own subject, with at most `concurrency` requests in
+    /// flight
+    ///
+    /// Every item is registered even when some fail. The results are returned in the order of
+    /// `items`, each with the subject it was registered under.
+    async fn register_many(
+        &self,
+        items: Vec<(String, UnregisteredSchema)>,
+        concurrency: usize,
+    ) -> Vec<(String, Result<u32, SchemaRegistryError>)> {
+        let mut results: Vec<_> = futures::stream::iter(items.into_iter().enumerate())
+            .map(|(index, (subject, schema))| async move {
+                let result = self
+                    .post_new_subject_version(&subject, &schema, false)
+                    .await;
+                (index, subject, result)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await;
+
+        results.sort_by_key(|(index, _, _)| *index);
+
+        results
+            .into_iter()
+            .map(|(_, subject, result)| (subject, result))
+            .collect()
+    }
+
+    /// Post a new schema and wait until it can be read back by id
+    ///
+    /// In clustered setups a freshly registered schema may not be readable from every replica
+    /// right away. This polls [`SchemaRegistryAPI::get_schema_by_id`] until it succeeds, giving
+    /// read-your-writes semantics, and fails with [`SchemaRegistryError::Timeout`] once
+    /// `timeout` elapses. Only a missing schema is polled again, any other error is returned
+    /// right away.
+    async fn register_and_await_readable(
+        &self,
+        subject: &str,
+        schema: &UnregisteredSchema,
+        normalize: bool,
+        timeout: Duration,
+    ) -> Result<u32, SchemaRegistryError> {
+        let id = self
+            .post_new_subject_version(subject, schema, normalize)
+            .await?;
+
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        loop {
+            match self.get_schema_by_id(id).await {
+                Ok(_) => return Ok(id),
+                Err(e) if !e.is_not_found() => return Err(e),
+                Err(_) if tokio::time::Instant::now() + POLL_INTERVAL < deadline => {
+                    tokio::time::sleep(POLL_INTERVAL).await;
+                }
+                Err(error) => {
+                    return Err(SchemaRegistryError::Timeout {
+                        message: format!(
+                            "schema id {} was not readable after {:?}: {}",
+                            id, timeout, error
+                        ),
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Extract the error type and additional info of a verbose incompatibility message, such as
+/// `{errorType:'READER_FIELD_MISSING_DEFAULT_VALUE', description:'...', additionalInfo:'age'}`
+#[cfg(feature = "avro")]
+fn parse_incompatibility(message: &str) -> Option<(&str, &str)> {
+    let attribute = |name: &str| {
+        let start = message.find(name)? + name.len();
+        let end = message[start..].find('\'')? + start;
+
+        Some(&message[start..end])
+    };
+
+    Some((attribute("errorType:'")?, attribute("additionalInfo:'")?))
 }
@@ -0,0 +1,229 @@
+//! Portable copies of a whole registry, taken with
+//! [`SchemaRegistryAPI::export_all`](crate::api::SchemaRegistryAPI::export_all) and restored
+//! with [`SchemaRegistryAPI::import_all`](crate::api::SchemaRegistryAPI::import_all).
+
+use std::collections::{BTreeMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::SchemaRegistryError;
+use crate::types::{ClusterConfig, Mode, Schema, SubjectConfig};
+
+/// A serializable copy of every subject, version, config and mode of a registry
+///
+/// Subjects and versions are kept sorted so the same registry always gives the same output.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RegistrySnapshot {
+    /// The global configuration
+    pub config: Option<ClusterConfig>,
+    /// The global resource mode
+    pub mode: Option<Mode>,
+    pub subjects: BTreeMap<String, SnapshotSubject>,
+}
+
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotSubject {
+    /// Every version of the subject, keyed by version
+    pub versions: BTreeMap<u32, SnapshotVersion>,
+    /// The subject configuration, `None` when the subject relies on the global configuration
+    pub config: Option<SubjectConfig>,
+    /// The subject resource mode, `None` when the subject relies on the global mode
+    pub mode: Option<Mode>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotVersion {
+    /// The id the schema is registered under
+    pub id: u32,
+    pub schema: Schema,
+}
+
+/// The differences between a live registry and a [`RegistrySnapshot`]
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct SnapshotDiff {
+    /// Subjects in the registry but not in the snapshot
+    pub added: Vec<String>,
+    /// Subjects in the snapshot but not in the registry
+    pub removed: Vec<String>,
+    /// Subjects in both whose versions, ids or schemas differ
+    pub changed: Vec<String>,
+    /// Subjects in both whose configuration differs
+    pub config_changed: Vec<String>,
+    /// Whether the global configuration differs
+    pub global_config_changed: bool,
+}
+
+impl SnapshotDiff {
+    /// Whether the registry matches the snapshot
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+            && self.config_changed.is_empty()
+            && !self.global_config_changed
+    }
+}
+
+/// The outcome of restoring a snapshot
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct ImportReport {
+    /// The outcome of each subject version, in the order they were restored
+    pub versions: Vec<ImportedVersion>,
+    /// The configs and modes that could not be restored
+    pub failed_settings: Vec<FailedSetting>,
+}
+
+impl ImportReport {
+    /// Whether every subject version was restored or already present, along with every
+    /// config and mode
+    pub fn is_success(&self) -> bool {
+        self.failed_settings.is_empty()
+            && self
+                .versions
+                .iter()
+                .all(|imported| !matches!(imported.outcome, ImportOutcome::Failed { .. }))
+    }
+
+    pub(crate) fn record_failure(
+        &mut self,
+        subject: Option<&str>,
+        setting: SnapshotSetting,
+        error: SchemaRegistryError,
+    ) {
+        self.failed_settings.push(FailedSetting {
+            subject: subject.map(str::to_owned),
+            setting,
+            message: error.to_string(),
+        });
+    }
+}
+
+/// A config or mode that could not be restored
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FailedSetting {
+    /// The subject of the setting, `None` for the global one
+    pub subject: Option<String>,
+    pub setting: SnapshotSetting,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SnapshotSetting {
+    Config,
+    Mode,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ImportedVersion {
+    pub subject: String,
+    pub version: u32,
+    pub outcome: ImportOutcome,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ImportOutcome {
+    Imported,
+    /// The subject version was already in the registry
+    Skipped,
+    Failed {
+        message: String,
+    },
+}
+
+/// Order the subject versions of a snapshot so every version comes after those it references.
+pub(crate) fn import_order(snapshot: &RegistrySnapshot) -> Vec<(&str, u32)> {
+    let mut ordered = Vec::new();
+    let mut visited = HashSet::new();
+
+    for (subject, snapshot_subject) in &snapshot.subjects {
+        for version in snapshot_subject.versions.keys() {
+            visit(snapshot, subject, *version, &mut visited, &mut ordered);
+        }
+    }
+
+    ordered
+}
+
+fn visit<'a>(
+    snapshot: &'a RegistrySnapshot,
+    subject: &str,
+    version: u32,
+    visited: &mut HashSet<(&'a str, u32)>,
+    ordered: &mut Vec<(&'a str, u32)>,
+) {
+    // references to versions outside the snapshot are expected to be in the registry already
+    let Some((subject, snapshot_subject)) = snapshot.subjects.get_key_value(subject) else {
+        return;
+    };
+    let Some(snapshot_version) = snapshot_subject.versions.get(&version) else {
+        return;
+    };
+
+    if !visited.insert((subject.as_str(), version)) {
+        return;
+    }
+
+    // earlier versions of a subject are registered before the later ones
+    for earlier in snapshot_subject.versions.range(..version).map(|(v, _)| *v) {
+        visit(snapshot, subject, earlier, visited, ordered);
+    }
+
+    for reference in snapshot_version.schema.references.iter().flatten() {
+        visit(
+            snapshot,
+            &reference.subject,
+            reference.version,
+            visited,
+            ordered,
+        );
+    }
+
+    ordered.push((subject.as_str(), version));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backup::{import_order, RegistrySnapshot, SnapshotSubject, SnapshotVersion};
+    use crate::types::{Reference, Schema, SchemaType};
+
+    fn version(id: u32, references: Vec<Reference>) -> SnapshotVersion {
+        SnapshotVersion {
+            id,
+            schema: Schema {
+                schema_type: SchemaType::Avro,
+                schema: "{}".into(),
+                references: Some(references),
+                metadata: None,
+                rule_set: None,
+            },
+        }
+    }
+
+    #[test]
+    fn referenced_versions_are_imported_first_and_in_order() {
+        let mut snapshot = RegistrySnapshot::default();
+        snapshot.subjects.insert(
+            "a-value".to_owned(),
+            SnapshotSubject {
+                versions: [(
+                    1,
+                    version(3, vec![Reference::new("B", "b-value").version(2)]),
+                )]
+                .into(),
+                ..Default::default()
+            },
+        );
+        snapshot.subjects.insert(
+            "b-value".to_owned(),
+            SnapshotSubject {
+                versions: [(1, version(1, vec![])), (2, version(2, vec![]))].into(),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            import_order(&snapshot),
+            [("b-value", 1), ("b-value", 2), ("a-value", 1)]
+        );
+    }
+}
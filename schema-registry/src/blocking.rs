@@ -0,0 +1,167 @@
+//! A blocking client for code that does not run inside an async runtime.
+//!
+//! [`BlockingSchemaRegistryClient`] wraps a [`SchemaRegistryClient`] and drives each call
+//! to completion on a runtime it owns, so it must not be used from within an async context.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use tokio::runtime::{Builder, Runtime};
+
+#[cfg(feature = "avro")]
+use crate::api::AvroSerializer;
+use crate::api::SchemaRegistryAPI;
+use crate::backup::{ImportReport, RegistrySnapshot, SnapshotDiff};
+use crate::client::config::SchemaRegistryConfig;
+use crate::client::SchemaRegistryClient;
+use crate::error::{ConfigurationError, SchemaRegistryError};
+use crate::types::{
+    ClusterConfig, ClusterId, CompatibilityLevel, CompatibilityResult, ContractPolicy,
+    ExporterConfig, ExporterStatus, Mode, RegistrationOutcome, RegistryFlavor, ResolvedSchema,
+    Schema, SchemaType, ServerVersion, StringSchema, Subject, SubjectConfig, SubjectVersion,
+    UnregisteredSchema, Version,
+};
+
+/// Generates blocking methods forwarding to the [`SchemaRegistryAPI`] method of the same name
+macro_rules! blocking {
+    ($($(#[$meta:meta])* fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $ret:ty;)*) => {
+        $(
+            $(#[$meta])*
+            #[doc = concat!("Blocking version of [`SchemaRegistryAPI::", stringify!($name), "`]")]
+            pub fn $name(&self $(, $arg: $ty)*) -> Result<$ret, SchemaRegistryError> {
+                self.runtime.block_on(self.inner.$name($($arg),*))
+            }
+        )*
+    };
+}
+
+/// A Schema Registry client whose calls block the current thread until they complete
+///
+/// Each call runs on a current-thread runtime owned by the client. Calling it from within
+/// an async runtime panics, use [`SchemaRegistryClient`] there instead.
+pub struct BlockingSchemaRegistryClient {
+    inner: SchemaRegistryClient,
+    runtime: Runtime,
+}
+
+impl BlockingSchemaRegistryClient {
+    /// Create a new blocking client from the provided configuration
+    pub fn from_conf(conf: SchemaRegistryConfig) -> Result<Self, SchemaRegistryError> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|source| ConfigurationError::Runtime { source })?;
+
+        let inner = {
+            let _guard = runtime.enter();
+            SchemaRegistryClient::from_conf(conf)?
+        };
+
+        Ok(Self { inner, runtime })
+    }
+
+    /// Get the async client behind this blocking client
+    pub fn inner(&self) -> &SchemaRegistryClient {
+        &self.inner
+    }
+
+    blocking! {
+        fn is_compatible(&self, subject: &str, version: Version, schema: &UnregisteredSchema) -> bool;
+        fn is_compatible_verbose(&self, subject: &str, version: Version, schema: &UnregisteredSchema) -> CompatibilityResult;
+        fn is_fully_compatible(&self, subject: &str, schema: &UnregisteredSchema) -> bool;
+        fn get_configuration(&self) -> ClusterConfig;
+        fn get_configuration_raw(&self) -> serde_json::Value;
+        fn update_configuration(&self, configuration: &ClusterConfig) -> ClusterConfig;
+        fn delete_configuration(&self) -> ClusterConfig;
+        fn get_subject_configuration(&self, subject: &str) -> SubjectConfig;
+        fn get_subject_configuration_with_default(&self, subject: &str, default_to_global: bool) -> SubjectConfig;
+        fn get_subject_configuration_raw(&self, subject: &str) -> serde_json::Value;
+        fn update_subject_configuration(&self, subject: &str, configuration: &SubjectConfig) -> SubjectConfig;
+        fn delete_subject_configuration(&self, subject: &str) -> SubjectConfig;
+        fn get_exporters(&self) -> Vec<String>;
+        fn get_contexts(&self) -> Vec<String>;
+        fn ping(&self) -> ();
+        fn get_cluster_id(&self) -> ClusterId;
+        fn get_server_version(&self) -> ServerVersion;
+        fn detect_flavor(&self) -> RegistryFlavor;
+        fn get_subjects_in_context(&self, context: &str, deleted: bool) -> Vec<String>;
+        fn create_exporter(&self, config: &ExporterConfig) -> String;
+        fn update_exporter(&self, name: &str, config: &ExporterConfig) -> String;
+        fn update_exporter_config(&self, name: &str, config: &HashMap<String, String>) -> String;
+        fn get_exporter(&self, name: &str) -> ExporterConfig;
+        fn get_exporter_config(&self, name: &str) -> HashMap<String, String>;
+        fn get_exporter_status(&self, name: &str) -> ExporterStatus;
+        fn pause_exporter(&self, name: &str) -> ();
+        fn reset_exporter(&self, name: &str) -> ();
+        fn resume_exporter(&self, name: &str) -> ();
+        fn delete_exporter(&self, name: &str) -> ();
+        fn get_global_resource_mode(&self) -> Mode;
+        fn update_global_resource_mode(&self, mode: Mode, force: bool) -> Mode;
+        fn get_subject_resource_mode(&self, subject: &str) -> Mode;
+        fn get_subject_resource_mode_with_default(&self, subject: &str, default_to_global: bool) -> Mode;
+        fn update_subject_resource_mode(&self, subject: &str, mode: Mode, force: bool) -> Mode;
+        fn delete_subject_mode(&self, subject: &str) -> Option<Mode>;
+        fn get_schema_by_id(&self, id: u32) -> Schema;
+        fn get_schema_by_id_raw(&self, id: u32) -> StringSchema;
+        fn get_schemas_types(&self) -> Vec<SchemaType>;
+        fn get_schema_subject_versions(&self, id: u32) -> Vec<SubjectVersion>;
+        fn get_subjects(&self, deleted: bool) -> Vec<String>;
+        fn get_subjects_filtered(&self, prefix: Option<&str>, context: Option<&str>, deleted: bool) -> Vec<String>;
+        fn get_subject_versions(&self, subject: &str) -> Vec<u32>;
+        fn get_subject_versions_with_deleted(&self, subject: &str, deleted: bool) -> Vec<u32>;
+        fn delete_subject(&self, subject: &str, permanent: bool) -> Vec<u32>;
+        fn delete_subject_forced(&self, subject: &str, permanent: bool, force: bool) -> Vec<u32>;
+        fn get_subject_version(&self, subject: &str, version: Version) -> Subject;
+        fn get_subject_version_opt(&self, subject: &str, version: Version, deleted: bool) -> Subject;
+        fn get_subject_version_raw(&self, subject: &str, version: Version) -> StringSchema;
+        fn post_new_subject_version(&self, subject: &str, schema: &UnregisteredSchema, normalize: bool) -> u32;
+        fn register_subject_schema_full(&self, subject: &str, schema: &UnregisteredSchema, normalize: bool) -> Subject;
+        fn lookup_subject_schema(&self, subject: &str, schema: &UnregisteredSchema, normalize: bool) -> Subject;
+        fn delete_subject_version(&self, subject: &str, version: Version, permanent: bool) -> u32;
+        fn get_subject_version_references(&self, subject: &str, version: Version) -> Vec<u32>;
+        fn delete_schema_everywhere(&self, id: u32, permanent: bool, force: bool) -> Vec<SubjectVersion>;
+        #[cfg(feature = "avro")]
+        fn get_avro_schema_by_id(&self, id: u32) -> apache_avro::Schema;
+        #[cfg(feature = "avro")]
+        fn avro_serializer(&self, subject: &str, schema: &UnregisteredSchema, normalize: bool) -> AvroSerializer;
+        #[cfg(feature = "avro")]
+        fn suggest_compatible_changes(&self, subject: &str, schema: &UnregisteredSchema) -> Vec<String>;
+        fn reference_closure(&self, subject: &str, version: Version) -> Vec<Subject>;
+        fn get_schema_with_references(&self, id: u32) -> ResolvedSchema;
+        fn deletion_impact(&self, subject: &str, version: Version) -> Vec<SubjectVersion>;
+        fn all_subject_modes(&self) -> HashMap<String, Mode>;
+        fn is_compatible_with_versions(&self, subject: &str, versions: &[u32], schema: &UnregisteredSchema) -> bool;
+        fn validate_version_chain(&self, subject: &str) -> Vec<(u32, u32, bool)>;
+        fn resolve_version(&self, subject: &str, version: Version) -> u32;
+        fn full_index(&self) -> Vec<(String, u32, u32)>;
+        fn frame_for_subject_version(&self, subject: &str, version: Version, payload: &[u8]) -> Vec<u8>;
+        fn changes_since(&self, watermark: HashMap<String, u32>) -> Vec<SubjectVersion>;
+        fn normalize_compare(&self, subject: &str, schema: &UnregisteredSchema) -> (String, String);
+        fn find_id_by_schema(&self, schema: &UnregisteredSchema) -> Option<u32>;
+        fn wait_for_versions(&self, subject: &str, count: usize, timeout: Duration) -> Vec<u32>;
+        fn patch_subject_configuration(&self, subject: &str, patch: SubjectConfig) -> SubjectConfig;
+        fn resolve_contract_policy(&self, subject: &str) -> ContractPolicy;
+        fn snapshot_compatibilities(&self) -> HashMap<String, Option<CompatibilityLevel>>;
+        fn restore_compatibilities(&self, snapshot: &HashMap<String, Option<CompatibilityLevel>>) -> ();
+        fn export_all(&self, deleted: bool) -> RegistrySnapshot;
+        fn import_all(&self, snapshot: &RegistrySnapshot) -> ImportReport;
+        fn diff_against_snapshot(&self, snapshot: &RegistrySnapshot) -> SnapshotDiff;
+        #[deprecated(note = "use `diff_against_snapshot` instead")]
+        fn diff_against_bundle(&self, bundle: &RegistrySnapshot) -> SnapshotDiff;
+        fn lookup_respecting_config(&self, subject: &str, schema: &UnregisteredSchema) -> Subject;
+        fn register_subject_version_detailed(&self, subject: &str, schema: &UnregisteredSchema, normalize: bool) -> RegistrationOutcome;
+        fn register_if_absent(&self, subject: &str, schema: &UnregisteredSchema, normalize: bool) -> (u32, bool);
+        fn register_topic_schemas(&self, topic: &str, key_schema: &UnregisteredSchema, value_schema: &UnregisteredSchema, normalize: bool) -> (u32, u32);
+        fn register_and_await_readable(&self, subject: &str, schema: &UnregisteredSchema, normalize: bool, timeout: Duration) -> u32;
+    }
+
+    /// Blocking version of [`SchemaRegistryAPI::register_many`]
+    pub fn register_many(
+        &self,
+        items: Vec<(String, UnregisteredSchema)>,
+        concurrency: usize,
+    ) -> Vec<(String, Result<u32, SchemaRegistryError>)> {
+        self.runtime
+            .block_on(self.inner.register_many(items, concurrency))
+    }
+}
@@ -0,0 +1,556 @@
+//! A client memoizing schemas fetched by id.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+
+use crate::api::SchemaRegistryAPI;
+use crate::client::config::SchemaRegistryConfig;
+use crate::client::SchemaRegistryClient;
+use crate::error::SchemaRegistryError;
+use crate::types::{
+    ClusterConfig, ClusterId, CompatibilityResult, ExporterConfig, ExporterStatus, Mode,
+    RegistryFlavor, Schema, SchemaType, ServerVersion, StringSchema, Subject, SubjectConfig,
+    SubjectVersion, UnregisteredSchema, Version,
+};
+
+/// How many schemas are cached unless configured otherwise
+const DEFAULT_CAPACITY: usize = 1000;
+
+/// Callback invoked with the schema id whenever a schema has to be fetched from the network
+pub type CacheMissCallback = Arc<dyn Fn(u32) + Send + Sync>;
+
+/// A [`SchemaRegistryAPI`] memoizing `get_schema_by_id` and `get_schema_by_id_raw`
+///
+/// Schemas are immutable once registered, so they are cached without expiry in a bounded
+/// cache evicting the least recently used schema. Every other call is delegated to the inner
+/// client as is.
+pub struct CachedSchemaRegistryClient<C = SchemaRegistryClient> {
+    inner: C,
+    schemas: Mutex<Lru<u32, Schema>>,
+    raw_schemas: Mutex<Lru<u32, StringSchema>>,
+    on_cache_miss: Option<CacheMissCallback>,
+}
+
+impl<C: SchemaRegistryAPI> CachedSchemaRegistryClient<C> {
+    /// Wrap a client, caching up to 1000 schemas
+    pub fn new(client: C) -> Self {
+        Self::with_capacity(client, DEFAULT_CAPACITY)
+    }
+
+    /// Wrap a client, caching up to `capacity` schemas
+    pub fn with_capacity(client: C, capacity: usize) -> Self {
+        Self {
+            inner: client,
+            schemas: Mutex::new(Lru::new(capacity)),
+            raw_schemas: Mutex::new(Lru::new(capacity)),
+            on_cache_miss: None,
+        }
+    }
+
+    /// Invoke a callback whenever `get_schema_by_id` or `get_schema_by_id_raw` misses the cache
+    ///
+    /// The callback receives the schema id and runs right before the schema is fetched from
+    /// the network, which makes it suitable for hit-rate instrumentation.
+    pub fn on_cache_miss(mut self, callback: CacheMissCallback) -> Self {
+        self.on_cache_miss = Some(callback);
+        self
+    }
+
+    /// The wrapped client
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Drop every cached schema
+    pub fn clear(&self) {
+        self.schemas.lock().expect("cache lock poisoned").clear();
+        self.raw_schemas
+            .lock()
+            .expect("cache lock poisoned")
+            .clear();
+    }
+}
+
+impl CachedSchemaRegistryClient {
+    /// Create a caching client from the provided configuration
+    ///
+    /// Caches up to 1000 schemas and invokes the callback set with
+    /// [`SchemaRegistryConfig::on_cache_miss`] on every miss.
+    pub fn from_conf(conf: SchemaRegistryConfig) -> Result<Self, SchemaRegistryError> {
+        let on_cache_miss = conf.on_cache_miss.clone();
+        let mut client = Self::new(SchemaRegistryClient::from_conf(conf)?);
+        client.on_cache_miss = on_cache_miss;
+
+        Ok(client)
+    }
+}
+
+#[async_trait::async_trait]
+impl<C: SchemaRegistryAPI> SchemaRegistryAPI for CachedSchemaRegistryClient<C> {
+    async fn get_schema_by_id(&self, id: u32) -> Result<Schema, SchemaRegistryError> {
+        if let Some(schema) = self.schemas.lock().expect("cache lock poisoned").get(&id) {
+            return Ok(schema);
+        }
+
+        if let Some(on_cache_miss) = &self.on_cache_miss {
+            on_cache_miss(id);
+        }
+
+        let schema = self.inner.get_schema_by_id(id).await?;
+        self.schemas
+            .lock()
+            .expect("cache lock poisoned")
+            .insert(id, schema.clone());
+
+        Ok(schema)
+    }
+
+    async fn get_schema_by_id_raw(&self, id: u32) -> Result<StringSchema, SchemaRegistryError> {
+        if let Some(schema) = self
+            .raw_schemas
+            .lock()
+            .expect("cache lock poisoned")
+            .get(&id)
+        {
+            return Ok(schema);
+        }
+
+        if let Some(on_cache_miss) = &self.on_cache_miss {
+            on_cache_miss(id);
+        }
+
+        let schema = self.inner.get_schema_by_id_raw(id).await?;
+        self.raw_schemas
+            .lock()
+            .expect("cache lock poisoned")
+            .insert(id, schema.clone());
+
+        Ok(schema)
+    }
+
+    async fn is_compatible(
+        &self,
+        subject: &str,
+        version: Version,
+        schema: &UnregisteredSchema,
+    ) -> Result<bool, SchemaRegistryError> {
+        self.inner.is_compatible(subject, version, schema).await
+    }
+
+    async fn is_compatible_verbose(
+        &self,
+        subject: &str,
+        version: Version,
+        schema: &UnregisteredSchema,
+    ) -> Result<CompatibilityResult, SchemaRegistryError> {
+        self.inner
+            .is_compatible_verbose(subject, version, schema)
+            .await
+    }
+
+    async fn is_fully_compatible(
+        &self,
+        subject: &str,
+        schema: &UnregisteredSchema,
+    ) -> Result<bool, SchemaRegistryError> {
+        self.inner.is_fully_compatible(subject, schema).await
+    }
+
+    async fn get_configuration(&self) -> Result<ClusterConfig, SchemaRegistryError> {
+        self.inner.get_configuration().await
+    }
+
+    async fn get_configuration_raw(&self) -> Result<serde_json::Value, SchemaRegistryError> {
+        self.inner.get_configuration_raw().await
+    }
+
+    async fn update_configuration(
+        &self,
+        configuration: &ClusterConfig,
+    ) -> Result<ClusterConfig, SchemaRegistryError> {
+        self.inner.update_configuration(configuration).await
+    }
+
+    async fn get_subject_configuration(
+        &self,
+        subject: &str,
+    ) -> Result<SubjectConfig, SchemaRegistryError> {
+        self.inner.get_subject_configuration(subject).await
+    }
+
+    async fn get_subject_configuration_with_default(
+        &self,
+        subject: &str,
+        default_to_global: bool,
+    ) -> Result<SubjectConfig, SchemaRegistryError> {
+        self.inner
+            .get_subject_configuration_with_default(subject, default_to_global)
+            .await
+    }
+
+    async fn get_subject_configuration_raw(
+        &self,
+        subject: &str,
+    ) -> Result<serde_json::Value, SchemaRegistryError> {
+        self.inner.get_subject_configuration_raw(subject).await
+    }
+
+    async fn update_subject_configuration(
+        &self,
+        subject: &str,
+        configuration: &SubjectConfig,
+    ) -> Result<SubjectConfig, SchemaRegistryError> {
+        self.inner
+            .update_subject_configuration(subject, configuration)
+            .await
+    }
+
+    async fn delete_configuration(&self) -> Result<ClusterConfig, SchemaRegistryError> {
+        self.inner.delete_configuration().await
+    }
+
+    async fn delete_subject_configuration(
+        &self,
+        subject: &str,
+    ) -> Result<SubjectConfig, SchemaRegistryError> {
+        self.inner.delete_subject_configuration(subject).await
+    }
+
+    async fn get_exporters(&self) -> Result<Vec<String>, SchemaRegistryError> {
+        self.inner.get_exporters().await
+    }
+
+    async fn get_contexts(&self) -> Result<Vec<String>, SchemaRegistryError> {
+        self.inner.get_contexts().await
+    }
+
+    async fn ping(&self) -> Result<(), SchemaRegistryError> {
+        self.inner.ping().await
+    }
+
+    async fn get_cluster_id(&self) -> Result<ClusterId, SchemaRegistryError> {
+        self.inner.get_cluster_id().await
+    }
+
+    async fn get_server_version(&self) -> Result<ServerVersion, SchemaRegistryError> {
+        self.inner.get_server_version().await
+    }
+
+    async fn detect_flavor(&self) -> Result<RegistryFlavor, SchemaRegistryError> {
+        self.inner.detect_flavor().await
+    }
+
+    async fn get_subjects_in_context(
+        &self,
+        context: &str,
+        deleted: bool,
+    ) -> Result<Vec<String>, SchemaRegistryError> {
+        self.inner.get_subjects_in_context(context, deleted).await
+    }
+
+    async fn create_exporter(
+        &self,
+        config: &ExporterConfig,
+    ) -> Result<String, SchemaRegistryError> {
+        self.inner.create_exporter(config).await
+    }
+
+    async fn update_exporter(
+        &self,
+        name: &str,
+        config: &ExporterConfig,
+    ) -> Result<String, SchemaRegistryError> {
+        self.inner.update_exporter(name, config).await
+    }
+
+    async fn update_exporter_config(
+        &self,
+        name: &str,
+        config: &HashMap<String, String>,
+    ) -> Result<String, SchemaRegistryError> {
+        self.inner.update_exporter_config(name, config).await
+    }
+
+    async fn get_exporter(&self, name: &str) -> Result<ExporterConfig, SchemaRegistryError> {
+        self.inner.get_exporter(name).await
+    }
+
+    async fn get_exporter_config(
+        &self,
+        name: &str,
+    ) -> Result<HashMap<String, String>, SchemaRegistryError> {
+        self.inner.get_exporter_config(name).await
+    }
+
+    async fn get_exporter_status(&self, name: &str) -> Result<ExporterStatus, SchemaRegistryError> {
+        self.inner.get_exporter_status(name).await
+    }
+
+    async fn pause_exporter(&self, name: &str) -> Result<(), SchemaRegistryError> {
+        self.inner.pause_exporter(name).await
+    }
+
+    async fn reset_exporter(&self, name: &str) -> Result<(), SchemaRegistryError> {
+        self.inner.reset_exporter(name).await
+    }
+
+    async fn resume_exporter(&self, name: &str) -> Result<(), SchemaRegistryError> {
+        self.inner.resume_exporter(name).await
+    }
+
+    async fn delete_exporter(&self, name: &str) -> Result<(), SchemaRegistryError> {
+        self.inner.delete_exporter(name).await
+    }
+
+    async fn get_global_resource_mode(&self) -> Result<Mode, SchemaRegistryError> {
+        self.inner.get_global_resource_mode().await
+    }
+
+    async fn update_global_resource_mode(
+        &self,
+        mode: Mode,
+        force: bool,
+    ) -> Result<Mode, SchemaRegistryError> {
+        self.inner.update_global_resource_mode(mode, force).await
+    }
+
+    async fn get_subject_resource_mode(&self, subject: &str) -> Result<Mode, SchemaRegistryError> {
+        self.inner.get_subject_resource_mode(subject).await
+    }
+
+    async fn get_subject_resource_mode_with_default(
+        &self,
+        subject: &str,
+        default_to_global: bool,
+    ) -> Result<Mode, SchemaRegistryError> {
+        self.inner
+            .get_subject_resource_mode_with_default(subject, default_to_global)
+            .await
+    }
+
+    async fn update_subject_resource_mode(
+        &self,
+        subject: &str,
+        mode: Mode,
+        force: bool,
+    ) -> Result<Mode, SchemaRegistryError> {
+        self.inner
+            .update_subject_resource_mode(subject, mode, force)
+            .await
+    }
+
+    async fn delete_subject_mode(
+        &self,
+        subject: &str,
+    ) -> Result<Option<Mode>, SchemaRegistryError> {
+        self.inner.delete_subject_mode(subject).await
+    }
+
+    async fn get_schemas_types(&self) -> Result<Vec<SchemaType>, SchemaRegistryError> {
+        self.inner.get_schemas_types().await
+    }
+
+    async fn get_schema_subject_versions(
+        &self,
+        id: u32,
+    ) -> Result<Vec<SubjectVersion>, SchemaRegistryError> {
+        self.inner.get_schema_subject_versions(id).await
+    }
+
+    async fn get_subjects(&self, deleted: bool) -> Result<Vec<String>, SchemaRegistryError> {
+        self.inner.get_subjects(deleted).await
+    }
+
+    async fn get_subjects_filtered<'a>(
+        &self,
+        prefix: Option<&'a str>,
+        context: Option<&'a str>,
+        deleted: bool,
+    ) -> Result<Vec<String>, SchemaRegistryError> {
+        self.inner
+            .get_subjects_filtered(prefix, context, deleted)
+            .await
+    }
+
+    async fn get_subject_versions(&self, subject: &str) -> Result<Vec<u32>, SchemaRegistryError> {
+        self.inner.get_subject_versions(subject).await
+    }
+
+    async fn get_subject_versions_with_deleted(
+        &self,
+        subject: &str,
+        deleted: bool,
+    ) -> Result<Vec<u32>, SchemaRegistryError> {
+        self.inner
+            .get_subject_versions_with_deleted(subject, deleted)
+            .await
+    }
+
+    async fn delete_subject(
+        &self,
+        subject: &str,
+        permanent: bool,
+    ) -> Result<Vec<u32>, SchemaRegistryError> {
+        self.inner.delete_subject(subject, permanent).await
+    }
+
+    async fn delete_subject_forced(
+        &self,
+        subject: &str,
+        permanent: bool,
+        force: bool,
+    ) -> Result<Vec<u32>, SchemaRegistryError> {
+        self.inner
+            .delete_subject_forced(subject, permanent, force)
+            .await
+    }
+
+    async fn get_subject_version(
+        &self,
+        subject: &str,
+        version: Version,
+    ) -> Result<Subject, SchemaRegistryError> {
+        self.inner.get_subject_version(subject, version).await
+    }
+
+    async fn get_subject_version_opt(
+        &self,
+        subject: &str,
+        version: Version,
+        deleted: bool,
+    ) -> Result<Subject, SchemaRegistryError> {
+        self.inner
+            .get_subject_version_opt(subject, version, deleted)
+            .await
+    }
+
+    async fn get_subject_version_raw(
+        &self,
+        subject: &str,
+        version: Version,
+    ) -> Result<StringSchema, SchemaRegistryError> {
+        self.inner.get_subject_version_raw(subject, version).await
+    }
+
+    async fn post_new_subject_version(
+        &self,
+        subject: &str,
+        schema: &UnregisteredSchema,
+        normalize: bool,
+    ) -> Result<u32, SchemaRegistryError> {
+        self.inner
+            .post_new_subject_version(subject, schema, normalize)
+            .await
+    }
+
+    async fn register_subject_schema_full(
+        &self,
+        subject: &str,
+        schema: &UnregisteredSchema,
+        normalize: bool,
+    ) -> Result<Subject, SchemaRegistryError> {
+        self.inner
+            .register_subject_schema_full(subject, schema, normalize)
+            .await
+    }
+
+    async fn lookup_subject_schema(
+        &self,
+        subject: &str,
+        schema: &UnregisteredSchema,
+        normalize: bool,
+    ) -> Result<Subject, SchemaRegistryError> {
+        self.inner
+            .lookup_subject_schema(subject, schema, normalize)
+            .await
+    }
+
+    async fn delete_subject_version(
+        &self,
+        subject: &str,
+        version: Version,
+        permanent: bool,
+    ) -> Result<u32, SchemaRegistryError> {
+        self.inner
+            .delete_subject_version(subject, version, permanent)
+            .await
+    }
+
+    async fn get_subject_version_references(
+        &self,
+        subject: &str,
+        version: Version,
+    ) -> Result<Vec<u32>, SchemaRegistryError> {
+        self.inner
+            .get_subject_version_references(subject, version)
+            .await
+    }
+}
+
+/// A bounded map evicting the least recently used entry
+pub(crate) struct Lru<K, V> {
+    capacity: usize,
+    tick: u64,
+    entries: HashMap<K, (V, u64)>,
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Lru<K, V> {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            tick: 0,
+            entries: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn get(&mut self, key: &K) -> Option<V> {
+        self.tick += 1;
+        let (value, used) = self.entries.get_mut(key)?;
+        *used = self.tick;
+
+        Some(value.clone())
+    }
+
+    pub(crate) fn insert(&mut self, key: K, value: V) {
+        self.tick += 1;
+
+        if !self.entries.contains_key(&key) && self.entries.len() >= self.capacity {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(key, _)| key.clone());
+
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+
+        self.entries.insert(key, (value, self.tick));
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::cache::Lru;
+
+    #[test]
+    fn least_recently_used_entry_is_evicted() {
+        let mut lru = Lru::new(2);
+
+        lru.insert(1, "one");
+        lru.insert(2, "two");
+        assert_eq!(lru.get(&1), Some("one"));
+
+        lru.insert(3, "three");
+
+        assert_eq!(lru.get(&1), Some("one"));
+        assert_eq!(lru.get(&2), None);
+        assert_eq!(lru.get(&3), Some("three"));
+    }
+}
@@ -2,14 +2,20 @@ use std::collections::HashMap;
 use std::fmt;
 use std::io::Write;
 use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
 
 use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
 use base64::write::EncoderWriter;
 use http::{header, HeaderMap, HeaderName, HeaderValue};
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+use reqwest::{tls, Certificate, Identity};
 use reqwest::{Client, Proxy};
 use tracing::warn;
 
-use crate::error::ConfigurationError;
+use crate::client::cache::CacheMissCallback;
+use crate::client::retry::RetryPolicy;
+use crate::error::{ConfigurationError, SchemaRegistryError};
 
 #[derive(Clone, Eq, PartialEq)]
 pub enum Authentication {
@@ -20,6 +26,10 @@ pub enum Authentication {
         username: String,
         password: Option<String>,
     },
+    ApiKey {
+        header_name: String,
+        key: String,
+    },
 }
 
 impl fmt::Debug for Authentication {
@@ -35,6 +45,13 @@ impl fmt::Debug for Authentication {
                     username
                 )
             }
+            Authentication::ApiKey { header_name, .. } => {
+                write!(
+                    f,
+                    "ApiKeyAuthentication {{ header_name: {}, key: ****** }}",
+                    header_name
+                )
+            }
         }
     }
 }
@@ -46,20 +63,90 @@ impl fmt::Display for Authentication {
             Authentication::Basic { username, .. } => {
                 write!(f, "Basic {}:******", username)
             }
+            Authentication::ApiKey { header_name, .. } => write!(f, "{}: ******", header_name),
         }
     }
 }
 
+/// Provides the `Authorization` header of each request, for credentials that change over
+/// time such as OAuth tokens
+#[async_trait::async_trait]
+pub trait AuthProvider: Send + Sync {
+    /// Get the `Authorization` header value of the next request
+    async fn header(&self) -> Result<HeaderValue, SchemaRegistryError>;
+}
+
+/// How a call is spread over the configured URLs
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum EndpointStrategy {
+    /// Send the call to every URL at once and keep the first successful response
+    #[default]
+    Broadcast,
+    /// Send the call to one URL after the other, in the configured order, until one succeeds
+    FailoverInOrder,
+    /// Like `FailoverInOrder`, starting from the next URL on every call
+    RoundRobin,
+}
+
 #[derive(Default, Clone)]
 pub struct SchemaRegistryConfig {
     /// Comma separated list of schema registry urls
     pub(crate) urls: Vec<String>,
     /// Optional authentication configuration
     pub(crate) authentication: Option<Authentication>,
+    /// Optional provider of the `Authorization` header of each request
+    pub(crate) auth_provider: Option<Arc<dyn AuthProvider>>,
     /// Optional proxy configuration
     pub(crate) proxy: Option<String>,
     /// Optional headers to be included in every request
     pub(crate) headers: Option<HashMap<String, String>>,
+    /// Whether permanent deletes are allowed
+    pub(crate) allow_permanent_delete: bool,
+    /// Optional timeout of a whole request
+    pub(crate) timeout: Option<Duration>,
+    /// Optional timeout of the connection phase of a request
+    pub(crate) connect_timeout: Option<Duration>,
+    /// Whether every request is sent on a fresh connection
+    pub(crate) disable_connection_reuse: bool,
+    /// Optional time an idle pooled connection is kept open for
+    pub(crate) pool_idle_timeout: Option<Duration>,
+    /// Optional maximum number of idle pooled connections per host
+    pub(crate) pool_max_idle_per_host: Option<usize>,
+    /// Whether write calls declare an explicit UTF-8 charset
+    pub(crate) explicit_charset: bool,
+    /// How a call is spread over the configured URLs
+    pub(crate) endpoint_strategy: EndpointStrategy,
+    /// Optional maximum number of URLs a call is sent to
+    pub(crate) max_failover_attempts: Option<usize>,
+    /// Whether recently failed URLs are deprioritized
+    pub(crate) url_health_tracking: bool,
+    /// Optional time the latest version of a subject is cached for
+    pub(crate) latest_version_ttl: Option<Duration>,
+    /// Optional maximum number of subject versions cached
+    pub(crate) subject_version_cache_capacity: Option<usize>,
+    /// Optional callback invoked whenever a schema misses the cache
+    pub(crate) on_cache_miss: Option<CacheMissCallback>,
+    /// Optional policy retrying calls while the schema registry is unavailable
+    pub(crate) retry: Option<RetryPolicy>,
+    /// Whether response bodies are logged at trace level
+    pub(crate) log_bodies: bool,
+    /// Optional maximum number of characters of a response body logged
+    pub(crate) log_body_limit: Option<usize>,
+    /// Optional Unix domain socket every connection is made over
+    #[cfg(all(feature = "uds", unix))]
+    pub(crate) unix_socket: Option<std::path::PathBuf>,
+    /// Optional minimum TLS version accepted when connecting to the schema registry
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    pub(crate) min_tls_version: Option<tls::Version>,
+    /// Additional certificate authorities trusted when connecting to the schema registry
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    pub(crate) root_certificates: Vec<Certificate>,
+    /// Optional client certificate presented to the schema registry
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    pub(crate) identity: Option<Identity>,
+    /// Whether invalid server certificates are accepted
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    pub(crate) danger_accept_invalid_certs: bool,
 }
 
 impl SchemaRegistryConfig {
@@ -69,11 +156,20 @@ impl SchemaRegistryConfig {
     }
 
     /// Set a URL to the configuration
+    ///
+    /// A comma separated list of URLs is split into one entry per URL.
     pub fn url<S>(mut self, url: S) -> Self
     where
         S: Into<String>,
     {
-        self.urls.push(url.into());
+        let url = url.into();
+
+        self.urls.extend(
+            url.split(',')
+                .map(str::trim)
+                .filter(|url| !url.is_empty())
+                .map(str::to_owned),
+        );
         self
     }
 
@@ -118,6 +214,37 @@ impl SchemaRegistryConfig {
         self
     }
 
+    /// Set the authentication configuration with an API key sent in a custom header
+    pub fn api_key_auth<'a, S>(mut self, header_name: &str, key: S) -> Self
+    where
+        S: Into<Option<&'a String>>,
+    {
+        if self.authentication.is_some() {
+            warn!("Overwriting existing authentication configuration");
+        }
+
+        let Some(key) = key.into() else {
+            warn!("API key auth not applied, provided key is none");
+            return self;
+        };
+
+        let header_name = header_name.to_owned();
+        let key = key.to_owned();
+
+        self.authentication = Some(Authentication::ApiKey { header_name, key });
+        self
+    }
+
+    /// Set a provider asked for the `Authorization` header of every request
+    ///
+    /// Unlike [`SchemaRegistryConfig::bearer_auth`], the header is not fixed when the client is
+    /// built, so rotating credentials are picked up. The provided header takes precedence over
+    /// the one of the authentication configuration.
+    pub fn auth_provider(mut self, provider: Arc<dyn AuthProvider>) -> Self {
+        self.auth_provider = Some(provider);
+        self
+    }
+
     /// Set the proxy configuration
     pub fn proxy<'a, S>(mut self, proxy: S) -> Self
     where
@@ -141,6 +268,202 @@ impl SchemaRegistryConfig {
         );
         self
     }
+
+    /// Allow permanent (hard) deletes of subjects and subject versions
+    ///
+    /// Disabled by default, so permanent deletes fail with
+    /// [`SchemaRegistryError::PermanentDeleteDisabled`](crate::error::SchemaRegistryError::PermanentDeleteDisabled)
+    /// unless explicitly enabled. Soft deletes are always allowed.
+    pub fn allow_permanent_delete(mut self, allow: bool) -> Self {
+        self.allow_permanent_delete = allow;
+        self
+    }
+
+    /// Bound how long a request may take, from connecting until the body is read
+    ///
+    /// Requests are not bounded by default.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Bound how long connecting to the schema registry may take
+    ///
+    /// Connections are not bounded by default.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Send every request on a fresh connection instead of reusing pooled ones
+    ///
+    /// Meant for diagnosing connection-level issues: each request pays for a new TCP
+    /// (and TLS) handshake, which noticeably increases latency and load on the registry.
+    pub fn disable_connection_reuse(mut self, disable: bool) -> Self {
+        self.disable_connection_reuse = disable;
+        self
+    }
+
+    /// Close pooled connections that have been idle for longer than `timeout`
+    ///
+    /// Defaults to reqwest's 90 seconds.
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Keep at most `max` idle connections per host in the pool
+    ///
+    /// Unbounded by default. Ignored when [connection reuse is
+    /// disabled](SchemaRegistryConfig::disable_connection_reuse).
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Declare `charset=utf-8` on the `Content-Type` of write calls
+    ///
+    /// Some strict servers reject request bodies without an explicit charset. Off by default.
+    pub fn explicit_charset(mut self, enabled: bool) -> Self {
+        self.explicit_charset = enabled;
+        self
+    }
+
+    /// Set how a call is spread over the configured URLs, defaults to
+    /// [`EndpointStrategy::Broadcast`]
+    ///
+    /// Calls that write to the registry are always sent to one URL after the other, following
+    /// the order of the strategy.
+    pub fn endpoint_strategy(mut self, strategy: EndpointStrategy) -> Self {
+        self.endpoint_strategy = strategy;
+        self
+    }
+
+    /// Limit how many of the configured URLs a call is sent to before giving up
+    ///
+    /// Bounds the latency of a failing call when many URLs are configured. Calls are sent
+    /// to every URL by default, and to at least one whatever the limit.
+    pub fn max_failover_attempts(mut self, attempts: usize) -> Self {
+        self.max_failover_attempts = Some(attempts.max(1));
+        self
+    }
+
+    /// Remember URLs that recently failed and deprioritize them for a cooldown
+    ///
+    /// Calls are first sent to the URLs known to be good, and only reach a URL that failed
+    /// within the last 30 seconds if all of those fail. Transport errors and 5xx responses
+    /// count as failures.
+    pub fn url_health_tracking(mut self, enabled: bool) -> Self {
+        self.url_health_tracking = enabled;
+        self
+    }
+
+    /// Set how long `get_subject_version_cached` caches the latest version of a subject
+    ///
+    /// Defaults to 30 seconds. Numbered versions are immutable and cached until evicted.
+    pub fn latest_version_ttl(mut self, ttl: Duration) -> Self {
+        self.latest_version_ttl = Some(ttl);
+        self
+    }
+
+    /// Set how many subject versions `get_subject_version_cached` keeps, evicting the least
+    /// recently used one beyond that
+    ///
+    /// Defaults to 1000.
+    pub fn subject_version_cache_capacity(mut self, capacity: usize) -> Self {
+        self.subject_version_cache_capacity = Some(capacity);
+        self
+    }
+
+    /// Invoke a callback with the schema id whenever a schema has to be fetched from the network
+    ///
+    /// Applies to clients built with
+    /// [`CachedSchemaRegistryClient::from_conf`](crate::client::cache::CachedSchemaRegistryClient::from_conf),
+    /// the plain client does not cache schemas.
+    pub fn on_cache_miss(mut self, callback: CacheMissCallback) -> Self {
+        self.on_cache_miss = Some(callback);
+        self
+    }
+
+    /// Retry calls to a URL while the schema registry is unavailable
+    ///
+    /// Calls are not retried by default. Each URL is retried on its own before failing over.
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Log the body of every response at trace level
+    ///
+    /// Bodies are truncated to [`SchemaRegistryConfig::log_body_limit`] characters. Meant for
+    /// debugging: streamed responses are read in full before being handed back. Off by default.
+    pub fn log_bodies(mut self, enabled: bool) -> Self {
+        self.log_bodies = enabled;
+        self
+    }
+
+    /// Set how many characters of a response body are logged, defaults to 4096
+    pub fn log_body_limit(mut self, limit: usize) -> Self {
+        self.log_body_limit = Some(limit);
+        self
+    }
+
+    /// Connect to the schema registry over a Unix domain socket instead of TCP
+    ///
+    /// The host and port of the configured URLs are ignored, only their scheme and path are
+    /// used: by convention, configure the URL as `http://localhost` (or
+    /// `http://localhost/<prefix>` when the registry is served under a path). Proxy settings
+    /// are ignored.
+    #[cfg(all(feature = "uds", unix))]
+    pub fn unix_socket<P: Into<std::path::PathBuf>>(mut self, path: P) -> Self {
+        self.unix_socket = Some(path.into());
+        self
+    }
+
+    /// Set the minimum TLS version accepted when connecting to the schema registry
+    ///
+    /// When unset, the minimum version is left to the TLS backend defaults.
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    pub fn min_tls_version(mut self, version: tls::Version) -> Self {
+        self.min_tls_version = Some(version);
+        self
+    }
+
+    /// Trust an additional certificate authority when connecting to the schema registry
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Trust an additional PEM encoded certificate authority when connecting to the schema
+    /// registry
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    pub fn add_root_certificate_pem(self, pem: &[u8]) -> Result<Self, ConfigurationError> {
+        let certificate = Certificate::from_pem(pem)
+            .map_err(|source| ConfigurationError::Certificate { source })?;
+
+        Ok(self.add_root_certificate(certificate))
+    }
+
+    /// Set the client certificate presented to the schema registry, for mutual TLS
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    pub fn identity(mut self, identity: Identity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    /// Accept any server certificate, including expired, self-signed or mismatched ones
+    ///
+    /// # Warning
+    ///
+    /// This disables certificate validation entirely, leaving every connection open to
+    /// man-in-the-middle attacks. Only use it against local or development clusters.
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.danger_accept_invalid_certs = accept;
+        self
+    }
 }
 
 pub fn build_auth_headers(
@@ -149,9 +472,20 @@ pub fn build_auth_headers(
     match auth {
         Authentication::Bearer { token } => bearer_auth(token),
         Authentication::Basic { username, password } => basic_auth(username, password.as_ref()),
+        Authentication::ApiKey { header_name, key } => api_key_auth(header_name, key),
     }
 }
 
+pub fn api_key_auth(
+    header_name: &str,
+    key: &str,
+) -> Result<(HeaderName, HeaderValue), ConfigurationError> {
+    let header_name = HeaderName::from_str(header_name)?;
+    let mut header_value = HeaderValue::from_str(key)?;
+    header_value.set_sensitive(true);
+    Ok((header_name, header_value))
+}
+
 pub fn bearer_auth(token: &str) -> Result<(HeaderName, HeaderValue), ConfigurationError> {
     let header_name = header::AUTHORIZATION;
     let mut header = HeaderValue::from_str(&format!("Bearer {}", token))?;
@@ -222,6 +556,59 @@ pub(crate) fn build_http_client(conf: &SchemaRegistryConfig) -> Result<Client, C
         client_builder = client_builder.proxy(proxy);
     }
 
+    if let Some(timeout) = conf.timeout {
+        client_builder = client_builder.timeout(timeout);
+    }
+
+    if let Some(timeout) = conf.connect_timeout {
+        client_builder = client_builder.connect_timeout(timeout);
+    }
+
+    if let Some(timeout) = conf.pool_idle_timeout {
+        client_builder = client_builder.pool_idle_timeout(timeout);
+    }
+
+    if conf.disable_connection_reuse {
+        client_builder = client_builder.pool_max_idle_per_host(0);
+    } else if let Some(max) = conf.pool_max_idle_per_host {
+        client_builder = client_builder.pool_max_idle_per_host(max);
+    }
+
+    #[cfg(all(feature = "uds", unix))]
+    if let Some(path) = &conf.unix_socket {
+        client_builder = client_builder.unix_socket(path.clone());
+    }
+
+    #[cfg(feature = "rustls-tls")]
+    {
+        client_builder = client_builder.use_rustls_tls();
+    }
+
+    #[cfg(all(feature = "native-tls", not(feature = "rustls-tls")))]
+    {
+        client_builder = client_builder.use_native_tls();
+    }
+
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    if let Some(version) = conf.min_tls_version {
+        client_builder = client_builder.min_tls_version(version);
+    }
+
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    for certificate in &conf.root_certificates {
+        client_builder = client_builder.add_root_certificate(certificate.clone());
+    }
+
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    if let Some(identity) = &conf.identity {
+        client_builder = client_builder.identity(identity.clone());
+    }
+
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    if conf.danger_accept_invalid_certs {
+        client_builder = client_builder.danger_accept_invalid_certs(true);
+    }
+
     let http_client = client_builder.build().map_err(ConfigurationError::from)?;
 
     Ok(http_client)
@@ -230,11 +617,12 @@ pub(crate) fn build_http_client(conf: &SchemaRegistryConfig) -> Result<Client, C
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
+    use std::time::Duration;
 
     use factori::factori;
     use factori::factori_impl::create;
 
-    use crate::client::config::{Authentication, SchemaRegistryConfig};
+    use crate::client::config::{build_http_client, Authentication, SchemaRegistryConfig};
 
     fn headers() -> HashMap<String, String> {
         HashMap::from([("x_app_id".to_owned(), "my-app".to_owned())])
@@ -263,6 +651,7 @@ mod tests {
         pub schema_registry_username: Option<String>,
         pub schema_registry_password: Option<String>,
         pub schema_registry_token: Option<String>,
+        pub schema_registry_api_key: Option<String>,
         pub schema_registry_proxy: Option<String>,
         pub schema_registry_headers: HashMap<String, String>,
     }
@@ -273,6 +662,7 @@ mod tests {
             schema_registry_username = None,
             schema_registry_password = None,
             schema_registry_token = None,
+            schema_registry_api_key = None,
             schema_registry_proxy = None,
             schema_registry_headers = HashMap::new(),
         }
@@ -289,6 +679,10 @@ mod tests {
             schema_registry_token = Some("sr-token".to_owned()),
         }
 
+        mixin with_api_key {
+            schema_registry_api_key = Some("sr-api-key".to_owned()),
+        }
+
         mixin with_proxy {
             schema_registry_proxy = Some("http://localhost:9999".to_owned())
         }
@@ -387,6 +781,24 @@ mod tests {
         ))
     }
 
+    #[test]
+    fn create_config_with_api_key_auth() {
+        let app = create!(MockEnvOptionalVars, :with_api_key);
+
+        let config = SchemaRegistryConfig::new()
+            .url(&app.schema_registry_url)
+            .api_key_auth("X-API-Key", app.schema_registry_api_key.as_ref());
+
+        let authentication = config.authentication.unwrap();
+
+        assert!(matches!(authentication, Authentication::ApiKey { .. }));
+        assert_eq!(
+            format!("{:?}", authentication),
+            "ApiKeyAuthentication { header_name: X-API-Key, key: ****** }"
+        );
+        assert_eq!(format!("{}", authentication), "X-API-Key: ******");
+    }
+
     #[test]
     fn add_additional_headers() {
         let app = create!(MockEnvOptionalVars, :with_headers);
@@ -399,4 +811,63 @@ mod tests {
         assert!(config.authentication.is_none());
         assert_eq!(config.headers.unwrap().len(), 1);
     }
+
+    #[test]
+    fn build_client_without_connection_reuse() {
+        let app = create!(MockEnvOptionalVars);
+
+        let config = SchemaRegistryConfig::new()
+            .url(&app.schema_registry_url)
+            .disable_connection_reuse(true);
+
+        assert!(config.disable_connection_reuse);
+        assert!(build_http_client(&config).is_ok());
+    }
+
+    #[test]
+    fn build_client_with_pool_settings() {
+        let app = create!(MockEnvOptionalVars);
+
+        let config = SchemaRegistryConfig::new()
+            .url(&app.schema_registry_url)
+            .pool_idle_timeout(Duration::from_secs(30))
+            .pool_max_idle_per_host(16);
+
+        assert_eq!(config.pool_idle_timeout, Some(Duration::from_secs(30)));
+        assert_eq!(config.pool_max_idle_per_host, Some(16));
+        assert!(build_http_client(&config).is_ok());
+    }
+
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    #[test]
+    fn build_client_with_min_tls_version() {
+        let app = create!(MockEnvOptionalVars);
+
+        let config = SchemaRegistryConfig::new()
+            .url(&app.schema_registry_url)
+            .min_tls_version(reqwest::tls::Version::TLS_1_2);
+
+        assert_eq!(config.min_tls_version, Some(reqwest::tls::Version::TLS_1_2));
+        assert!(build_http_client(&config).is_ok());
+    }
+
+    #[cfg(feature = "native-tls")]
+    #[test]
+    fn build_client_with_native_tls() {
+        let app = create!(MockEnvOptionalVars);
+
+        let config = SchemaRegistryConfig::new().url(&app.schema_registry_url);
+
+        assert!(build_http_client(&config).is_ok());
+    }
+
+    #[cfg(feature = "rustls-tls")]
+    #[test]
+    fn build_client_with_rustls_tls() {
+        let app = create!(MockEnvOptionalVars);
+
+        let config = SchemaRegistryConfig::new().url(&app.schema_registry_url);
+
+        assert!(build_http_client(&config).is_ok());
+    }
 }
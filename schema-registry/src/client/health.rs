@@ -0,0 +1,58 @@
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+
+use crate::error::SchemaRegistryError;
+
+/// How long a URL that failed is deprioritized for
+const COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Remembers recently failed URLs so calls can try known-good URLs first.
+#[derive(Debug, Default)]
+pub(crate) struct UrlHealth {
+    failed_at: DashMap<String, Instant>,
+}
+
+impl UrlHealth {
+    /// Whether the URL failed within the cooldown
+    pub(crate) fn is_cooling_down(&self, url: &str) -> bool {
+        self.failed_at
+            .get(url)
+            .is_some_and(|failed_at| failed_at.elapsed() < COOLDOWN)
+    }
+
+    /// Record the outcome of a call to the URL.
+    ///
+    /// Only errors showing the URL is unavailable count as failures, any other response
+    /// proves it reachable.
+    pub(crate) fn record<T>(&self, url: &str, result: &Result<T, SchemaRegistryError>) {
+        match result {
+            Err(error) if error.is_unavailable() => {
+                self.failed_at.insert(url.to_owned(), Instant::now());
+            }
+            _ => {
+                self.failed_at.remove(url);
+            }
+        }
+    }
+
+    /// Wrap the calls so their outcome is recorded against their URL.
+    pub(crate) fn track<'a, T: Send + 'a>(
+        &'a self,
+        calls: Vec<(&'a str, BoxFuture<'a, Result<T, SchemaRegistryError>>)>,
+    ) -> Vec<BoxFuture<'a, Result<T, SchemaRegistryError>>> {
+        calls
+            .into_iter()
+            .map(|(url, call)| {
+                async move {
+                    let result = call.await;
+                    self.record(url, &result);
+                    result
+                }
+                .boxed()
+            })
+            .collect()
+    }
+}
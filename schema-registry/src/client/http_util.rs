@@ -1,33 +1,77 @@
+use std::time::{Duration, SystemTime};
+
 use futures::future::BoxFuture;
+use http::{header, HeaderMap};
 use serde::de::DeserializeOwned;
+use serde::Deserialize;
 
-use crate::error::HttpCallError;
+use crate::error::{HttpCallError, SchemaRegistryError};
 
 pub const VND_SCHEMA_REGISTRY_V1_JSON: &str = "application/vnd.schemaregistry.v1+json";
+pub const VND_SCHEMA_REGISTRY_V1_JSON_UTF8: &str =
+    "application/vnd.schemaregistry.v1+json; charset=utf-8";
 
 /// Execute a collection of async calls and return the first successful result.
 /// If all calls fail, return the last error.
-pub async fn exec_calls<T>(
-    calls: Vec<BoxFuture<'_, Result<T, HttpCallError>>>,
-) -> Result<T, HttpCallError> {
-    let (result, remaining) = futures::future::select_ok(calls.into_iter()).await?;
+pub async fn exec_calls<T, E>(calls: Vec<BoxFuture<'_, Result<T, E>>>) -> Result<T, E> {
+    let (result, remaining) = futures::future::select_ok(calls).await?;
     remaining.into_iter().for_each(drop);
     Ok(result)
 }
 
+/// Execute a collection of async calls one after the other and return the first successful result.
+/// Only moves on to the next call when the registry is unavailable, any other error is returned
+/// as is. If all calls fail, return the last error.
+///
+/// # Panics
+///
+/// Panics if `calls` is empty.
+pub async fn exec_calls_failover<T>(
+    calls: Vec<BoxFuture<'_, Result<T, SchemaRegistryError>>>,
+) -> Result<T, SchemaRegistryError> {
+    let mut last_error = None;
+
+    for call in calls {
+        match call.await {
+            Ok(result) => return Ok(result),
+            Err(e) if e.is_unavailable() => last_error = Some(e),
+            Err(e) => return Err(e),
+        }
+    }
+
+    Err(last_error.expect("calls provided to exec_calls_failover were empty"))
+}
+
+/// The error body returned by the Schema Registry
+#[derive(Deserialize)]
+struct ErrorBody {
+    error_code: i32,
+    message: String,
+}
+
+/// An error body missing some of the fields of [`ErrorBody`]
+#[derive(Default, Deserialize)]
+struct PartialErrorBody {
+    error_code: Option<i32>,
+    message: Option<String>,
+}
+
 /// Parse a response into a JSON value and return the result or an error.
 ///
-/// If the response is successful, tries to parse the JSON value into the desired type.
-/// If the response is not successful, tries to parse the JSON value into a `JsonValue` and return an error.
+/// If the response is successful, tries to parse the JSON value into the desired type, an empty
+/// body being parsed as `null` so it suits `()` and `Option`s.
+/// If the response is not successful, maps the registry's `error_code` to its semantic error,
+/// falling back to an `UpstreamError` carrying the raw body.
 pub async fn parse_response<T: DeserializeOwned>(
     response: reqwest::Response,
-) -> Result<T, HttpCallError> {
+) -> Result<T, SchemaRegistryError> {
     let status = response.status();
     let host = response.url().to_string();
+    let retry_after = retry_after(response.headers());
     let bytes = response.bytes().await?;
 
     match status.as_u16() {
-        200..=299 => match serde_json::from_slice::<T>(&bytes) {
+        200..=299 => match serde_json::from_slice::<T>(json_body(&bytes)) {
             Ok(parsed) => Ok(parsed),
             Err(source) => {
                 let body = String::from_utf8_lossy(&bytes);
@@ -36,13 +80,241 @@ pub async fn parse_response<T: DeserializeOwned>(
                     body: String::from(body),
                     target: std::any::type_name::<T>(),
                     source: Box::new(source),
-                })
+                }
+                .into())
             }
         },
-        _ => Err(HttpCallError::UpstreamError {
-            url: host,
-            status: status.as_u16(),
-            body: String::from_utf8_lossy(&bytes).to_string(),
-        }),
+        _ => Err(upstream_error(host, status.as_u16(), retry_after, &bytes)),
+    }
+}
+
+/// The JSON body of a successful response, `null` when it is empty
+fn json_body(bytes: &[u8]) -> &[u8] {
+    if bytes.iter().all(u8::is_ascii_whitespace) {
+        b"null"
+    } else {
+        bytes
+    }
+}
+
+/// Return the body of a response as text, or an error carrying its body otherwise.
+///
+/// Unlike `parse_response`, the body of a successful response is not parsed as JSON, which suits
+/// endpoints returning a bare schema.
+pub async fn parse_text_response(
+    response: reqwest::Response,
+) -> Result<String, SchemaRegistryError> {
+    let status = response.status();
+    let host = response.url().to_string();
+    let retry_after = retry_after(response.headers());
+    let bytes = response.bytes().await?;
+
+    match status.as_u16() {
+        200..=299 => Ok(String::from_utf8_lossy(&bytes).into_owned()),
+        _ => Err(upstream_error(host, status.as_u16(), retry_after, &bytes)),
+    }
+}
+
+/// Return the response if it is successful, or an error carrying its body otherwise.
+///
+/// Unlike `parse_response`, the body of a successful response is left untouched so it can be streamed.
+pub async fn check_response(
+    response: reqwest::Response,
+) -> Result<reqwest::Response, SchemaRegistryError> {
+    let status = response.status();
+
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let host = response.url().to_string();
+    let retry_after = retry_after(response.headers());
+    let bytes = response.bytes().await?;
+
+    Err(upstream_error(host, status.as_u16(), retry_after, &bytes))
+}
+
+/// Build the error for an unsuccessful response from its body.
+///
+/// Responses carrying a `Retry-After` header are kept as an `UpstreamError` so the delay is
+/// not lost to the semantic error of their body.
+pub fn upstream_error(
+    url: String,
+    status: u16,
+    retry_after: Option<Duration>,
+    bytes: &[u8],
+) -> SchemaRegistryError {
+    if retry_after.is_none() {
+        if let Ok(error) = serde_json::from_slice::<ErrorBody>(bytes) {
+            return SchemaRegistryError::from_error_code(error.error_code, error.message);
+        }
+    }
+
+    let partial = serde_json::from_slice::<PartialErrorBody>(bytes).unwrap_or_default();
+
+    HttpCallError::UpstreamError {
+        url,
+        status,
+        body: String::from_utf8_lossy(bytes).to_string(),
+        error_code: partial.error_code,
+        message: partial.message,
+        retry_after,
+    }
+    .into()
+}
+
+/// Parse the `Retry-After` header, given either in seconds or as an HTTP date.
+///
+/// Dates in the past give a zero delay.
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(header::RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use http::{header, HeaderMap, HeaderValue};
+
+    use crate::client::http_util::{parse_response, retry_after, upstream_error};
+    use crate::error::{HttpCallError, SchemaRegistryError};
+    use crate::types::Id;
+
+    const URL: &str = "http://localhost:8081/subjects/book-value";
+
+    fn response(status: u16, body: &'static str) -> reqwest::Response {
+        http::Response::builder()
+            .status(status)
+            .body(body)
+            .unwrap()
+            .into()
+    }
+
+    #[tokio::test]
+    async fn empty_successful_body_parses_as_unit() {
+        assert!(parse_response::<()>(response(204, "")).await.is_ok());
+        assert!(parse_response::<()>(response(200, "")).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn empty_body_is_still_an_error_for_values() {
+        let result = parse_response::<Id>(response(200, "")).await;
+
+        assert!(matches!(
+            result,
+            Err(SchemaRegistryError::HttpCall(HttpCallError::JsonParse { ref body, .. }))
+                if body.is_empty()
+        ));
+    }
+
+    #[test]
+    fn complete_error_body_maps_to_semantic_variant() {
+        let error = upstream_error(
+            URL.to_owned(),
+            404,
+            None,
+            br#"{"error_code":40401,"message":"Subject 'book-value' not found."}"#,
+        );
+
+        assert!(matches!(error, SchemaRegistryError::SubjectNotFound { .. }));
+        assert!(error.is_subject_not_found());
+        assert!(!error.is_version_not_found());
+    }
+
+    #[test]
+    fn partial_error_body_keeps_the_error_code() {
+        let error = upstream_error(URL.to_owned(), 404, None, br#"{"error_code":40402}"#);
+
+        assert!(matches!(
+            error,
+            SchemaRegistryError::HttpCall(HttpCallError::UpstreamError {
+                error_code: Some(40402),
+                message: None,
+                ..
+            })
+        ));
+        assert!(error.is_version_not_found());
+        assert!(!error.is_schema_not_found());
+    }
+
+    #[test]
+    fn unrecognized_error_body_is_kept_raw() {
+        let error = upstream_error(URL.to_owned(), 502, None, b"Bad Gateway");
+
+        assert!(matches!(
+            error,
+            SchemaRegistryError::HttpCall(HttpCallError::UpstreamError {
+                status: 502,
+                error_code: None,
+                message: None,
+                ref body,
+                ..
+            }) if body == "Bad Gateway"
+        ));
+        assert!(!error.is_subject_not_found());
+    }
+
+    #[test]
+    fn retry_after_is_parsed_from_seconds() {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::RETRY_AFTER, HeaderValue::from_static("120"));
+
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn retry_after_is_parsed_from_an_http_date() {
+        let date = SystemTime::now() + Duration::from_secs(60);
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::RETRY_AFTER,
+            HeaderValue::from_str(&httpdate::fmt_http_date(date)).unwrap(),
+        );
+
+        let delay = retry_after(&headers).unwrap();
+
+        assert!(delay > Duration::from_secs(55) && delay <= Duration::from_secs(60));
+    }
+
+    #[test]
+    fn retry_after_in_the_past_is_zero() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn retry_after_is_kept_on_the_error() {
+        let error = upstream_error(
+            URL.to_owned(),
+            429,
+            Some(Duration::from_secs(3)),
+            br#"{"error_code":429,"message":"Too many requests"}"#,
+        );
+
+        assert_eq!(error.retry_after(), Some(Duration::from_secs(3)));
+        assert!(matches!(
+            error,
+            SchemaRegistryError::HttpCall(HttpCallError::UpstreamError {
+                status: 429,
+                error_code: Some(429),
+                ..
+            })
+        ));
     }
 }
@@ -1,29 +1,77 @@
 use std::collections::HashMap;
-use std::sync::Arc;
-
-use futures::FutureExt;
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures::future::BoxFuture;
+use futures::io::AsyncRead;
+use futures::{FutureExt, Stream, StreamExt, TryStreamExt};
 use http::header;
 
 use crate::api::SchemaRegistryAPI;
-use crate::client::config::SchemaRegistryConfig;
-use crate::client::http_util::{exec_calls, parse_response, VND_SCHEMA_REGISTRY_V1_JSON};
-use crate::error::SchemaRegistryError;
+use crate::client::cache::Lru;
+use crate::client::config::{EndpointStrategy, SchemaRegistryConfig};
+use crate::client::health::UrlHealth;
+use crate::client::http_util::{
+    check_response, exec_calls, exec_calls_failover, parse_response, parse_text_response,
+    VND_SCHEMA_REGISTRY_V1_JSON, VND_SCHEMA_REGISTRY_V1_JSON_UTF8,
+};
+use crate::client::report::ReportCollector;
+use crate::client::send::{SendOptions, SendWith};
+use crate::error::{ConfigurationError, SchemaRegistryError};
 use crate::types::{
-    ClusterConfig, CompatibilityCheck, ExporterConfig, ExporterStatus, Id, Mode, ResourceMode,
-    Schema, SchemaType, StringSchema, Subject, SubjectConfig, SubjectVersion, UnregisteredSchema,
-    Version,
+    ClusterConfig, ClusterId, CompatibilityCheck, CompatibilityResult, ExporterConfig,
+    ExporterStatus, FailoverReport, Id, Mode, RegisteredSchema, RegistryFlavor, ResourceMode,
+    Schema, SchemaType, ServerVersion, StringSchema, Subject, SubjectConfig, SubjectVersion,
+    UnregisteredSchema, Version,
 };
 
+pub mod cache;
 pub mod config;
+mod health;
 mod http_util;
+mod report;
+pub mod retry;
+mod send;
+
+/// How many compatibility checks `check_stream` runs at once
+const CHECK_STREAM_CONCURRENCY: usize = 8;
+
+/// How many latest versions `subjects_stream` fetches at once
+const SUBJECTS_STREAM_CONCURRENCY: usize = 8;
+
+/// How long the latest version of a subject is cached for unless configured otherwise
+const DEFAULT_LATEST_VERSION_TTL: Duration = Duration::from_secs(30);
+
+/// How many subject versions are cached unless configured otherwise
+const DEFAULT_SUBJECT_VERSION_CACHE_CAPACITY: usize = 1000;
+
+/// How many characters of a response body are logged unless configured otherwise
+const DEFAULT_LOG_BODY_LIMIT: usize = 4096;
+
+/// Subject versions keyed by subject and version, with the time they were fetched
+type SubjectVersionCache = Lru<(String, Version), (Subject, Instant)>;
 
 /// A simple client for interacting with a Confluent Schema Registry.
 ///
-/// This client is a thin wrapper around the `reqwest` HTTP client.
+/// This client is a thin wrapper around the `reqwest` HTTP client. Wrap it in a
+/// [`CachedSchemaRegistryClient`](cache::CachedSchemaRegistryClient) to cache schemas by id.
 #[derive(Clone)]
 pub struct SchemaRegistryClient {
     urls: Arc<[String]>,
     http: reqwest::Client,
+    allow_permanent_delete: bool,
+    content_type: &'static str,
+    subject_versions: Arc<Mutex<SubjectVersionCache>>,
+    latest_version_ttl: Duration,
+    endpoint_strategy: EndpointStrategy,
+    /// Index of the URL the next round robin call starts from
+    next_url: Arc<AtomicUsize>,
+    max_failover_attempts: usize,
+    health: Option<Arc<UrlHealth>>,
+    report: Option<Arc<ReportCollector>>,
+    send: Arc<SendOptions>,
 }
 
 impl SchemaRegistryClient {
@@ -32,260 +80,1011 @@ impl SchemaRegistryClient {
     /// This is the simplest way to create a new `SchemaRegistryClient`.
     /// However, if you need to customize the client, you should use `from_conf` instead.
     pub fn from_url(url: &str) -> Result<Self, SchemaRegistryError> {
-        let urls = Arc::from([url.to_owned()]);
-        let http = config::build_http_client(&SchemaRegistryConfig::new().url(url))?;
+        Self::from_conf(SchemaRegistryConfig::new().url(url))
+    }
+
+    /// Create a new `SchemaRegistryClient` from a `SchemaRegistryConfig`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `SchemaRegistryConfig` is invalid or if the HTTP client cannot be created.
+    pub fn from_conf(conf: SchemaRegistryConfig) -> Result<Self, SchemaRegistryError> {
+        let http = config::build_http_client(&conf)?;
+
+        Ok(Self::with_http(conf, http))
+    }
+
+    /// Create a new `SchemaRegistryClient` sending requests through a pre-built HTTP client.
+    ///
+    /// The HTTP client is used as is: connection, TLS, proxy and header settings are left to
+    /// it, and every other setting takes its default value.
+    pub fn from_client(urls: Vec<String>, http: reqwest::Client) -> Self {
+        let conf = SchemaRegistryConfig {
+            urls,
+            ..SchemaRegistryConfig::default()
+        };
+
+        Self::with_http(conf, http)
+    }
+
+    fn with_http(conf: SchemaRegistryConfig, http: reqwest::Client) -> Self {
+        let urls = Arc::from(conf.urls.clone());
+        let allow_permanent_delete = conf.allow_permanent_delete;
+        let content_type = if conf.explicit_charset {
+            VND_SCHEMA_REGISTRY_V1_JSON_UTF8
+        } else {
+            VND_SCHEMA_REGISTRY_V1_JSON
+        };
+        let latest_version_ttl = conf
+            .latest_version_ttl
+            .unwrap_or(DEFAULT_LATEST_VERSION_TTL);
+        let subject_version_cache_capacity = conf
+            .subject_version_cache_capacity
+            .unwrap_or(DEFAULT_SUBJECT_VERSION_CACHE_CAPACITY);
+        let max_failover_attempts = conf.max_failover_attempts.unwrap_or(usize::MAX);
+        let send = Arc::new(SendOptions {
+            retry: conf.retry.clone(),
+            auth: conf.auth_provider.clone(),
+            log_bodies: conf
+                .log_bodies
+                .then(|| conf.log_body_limit.unwrap_or(DEFAULT_LOG_BODY_LIMIT)),
+        });
+        let health = conf
+            .url_health_tracking
+            .then(|| Arc::new(UrlHealth::default()));
+
+        Self {
+            http,
+            urls,
+            allow_permanent_delete,
+            content_type,
+            subject_versions: Arc::new(Mutex::new(Lru::new(subject_version_cache_capacity))),
+            latest_version_ttl,
+            endpoint_strategy: conf.endpoint_strategy,
+            next_url: Arc::new(AtomicUsize::new(0)),
+            max_failover_attempts,
+            health,
+            report: None,
+            send,
+        }
+    }
+
+    /// The schema registry URLs this client sends requests to.
+    pub fn urls(&self) -> &[String] {
+        &self.urls
+    }
+
+    /// Get a handle that sends every call to the provided URL only.
+    ///
+    /// Useful to debug a specific replica. The handle shares the underlying HTTP client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is not one of the configured URLs.
+    pub fn at_url(&self, url: &str) -> Result<Self, SchemaRegistryError> {
+        if !self.urls.iter().any(|configured| configured == url) {
+            let url = url.to_owned();
+            return Err(ConfigurationError::UnknownUrl { url }.into());
+        }
+
+        Ok(Self {
+            urls: Arc::from([url.to_owned()]),
+            ..self.clone()
+        })
+    }
+
+    /// Get the schema identified by the provided id as a stream of bytes.
+    ///
+    /// The response body is not buffered, which allows large schemas to be parsed incrementally.
+    pub async fn get_schema_stream_by_id(
+        &self,
+        id: u32,
+    ) -> Result<impl AsyncRead + Send + Unpin, SchemaRegistryError> {
+        let mut http_calls = Vec::with_capacity(self.urls.len());
+
+        for base_url in self.urls.iter() {
+            let http = self.http.clone();
+            let send = self.send.clone();
+            let url = format!("{}/schemas/ids/{}", base_url, id);
+
+            let call = async move {
+                let response = http
+                    .get(&url)
+                    .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .send_with(&send)
+                    .await?;
+
+                check_response(response).await
+            }
+            .boxed();
+
+            http_calls.push((base_url.as_str(), call));
+        }
+
+        let response = self.exec_calls(http_calls).await?;
+
+        let reader = response
+            .bytes_stream()
+            .map_err(std::io::Error::other)
+            .into_async_read();
+
+        Ok(reader)
+    }
+
+    /// Check the compatibility of each schema with the latest version of the subject.
+    ///
+    /// Results are yielded as `(index, result)` pairs as soon as each check finishes, so they
+    /// may come out of order. At most 8 checks run concurrently.
+    pub fn check_stream<'a>(
+        &'a self,
+        subject: &'a str,
+        schemas: Vec<UnregisteredSchema>,
+    ) -> impl Stream<Item = (usize, Result<bool, SchemaRegistryError>)> + 'a {
+        futures::stream::iter(schemas.into_iter().enumerate())
+            .map(move |(index, schema)| async move {
+                let result = self.is_compatible(subject, Version::Latest, &schema).await;
+
+                (index, result)
+            })
+            .buffer_unordered(CHECK_STREAM_CONCURRENCY)
+    }
+
+    /// Stream the latest version of every subject, including soft deleted ones when `deleted`
+    /// is set.
+    ///
+    /// The subject list is fetched first, then the latest versions are fetched lazily, at most
+    /// 8 at once, and yielded in the order of the subject list. A failure to fetch one subject
+    /// is yielded as an item without ending the stream.
+    pub fn subjects_stream(
+        &self,
+        deleted: bool,
+    ) -> impl Stream<Item = Result<Subject, SchemaRegistryError>> + '_ {
+        futures::stream::once(self.get_subjects(deleted))
+            .map_ok(|subjects| futures::stream::iter(subjects.into_iter().map(Ok)))
+            .try_flatten()
+            .map(
+                move |subject: Result<String, SchemaRegistryError>| async move {
+                    self.get_subject_version_opt(&subject?, Version::Latest, deleted)
+                        .await
+                },
+            )
+            .buffered(SUBJECTS_STREAM_CONCURRENCY)
+    }
+
+    /// Get a specific version of the subject, serving repeated calls from a cache.
+    ///
+    /// Numbered versions are immutable and cached until evicted, while the latest version is
+    /// cached for the configured `latest_version_ttl`. The cache holds the configured
+    /// `subject_version_cache_capacity` versions, evicting the least recently used one.
+    pub async fn get_subject_version_cached(
+        &self,
+        subject: &str,
+        version: Version,
+    ) -> Result<Subject, SchemaRegistryError> {
+        let key = (subject.to_owned(), version);
+
+        let cached = self
+            .subject_versions
+            .lock()
+            .expect("cache lock poisoned")
+            .get(&key);
+
+        if let Some((cached, fetched_at)) = cached {
+            if version != Version::Latest || fetched_at.elapsed() < self.latest_version_ttl {
+                return Ok(cached);
+            }
+        }
+
+        let fetched = self.get_subject_version(subject, version).await?;
+        self.subject_versions
+            .lock()
+            .expect("cache lock poisoned")
+            .insert(key, (fetched.clone(), Instant::now()));
+
+        Ok(fetched)
+    }
+
+    /// Build a decoder of framed Avro payloads, the consumer counterpart of
+    /// [`SchemaRegistryAPI::avro_serializer`].
+    ///
+    /// The returned closure strips the wire format framing, fetches the writer schema by the
+    /// embedded id and decodes the payload with it. Parsed schemas are cached for the lifetime
+    /// of the closure and its clones. Schemas with references are not supported.
+    #[cfg(feature = "avro")]
+    pub fn avro_deserializer(
+        &self,
+    ) -> impl Fn(&[u8]) -> BoxFuture<'static, Result<apache_avro::types::Value, SchemaRegistryError>>
+           + Clone
+           + Send
+           + Sync {
+        let client = self.clone();
+        let schemas: Arc<dashmap::DashMap<u32, Arc<apache_avro::Schema>>> = Arc::default();
+
+        move |bytes| {
+            let client = client.clone();
+            let schemas = schemas.clone();
+            let bytes = bytes.to_vec();
+
+            async move {
+                let (id, mut payload) = crate::wire::decode_wire_format(&bytes)?;
+
+                let schema = match schemas.get(&id) {
+                    Some(schema) => schema.clone(),
+                    None => {
+                        let schema = Arc::new(client.get_avro_schema_by_id(id).await?);
+                        schemas.insert(id, schema.clone());
+                        schema
+                    }
+                };
+
+                apache_avro::from_avro_datum(&schema, &mut payload, None)
+                    .map_err(|e| SchemaRegistryError::Other(e.into()))
+            }
+            .boxed()
+        }
+    }
+
+    /// Establish a pooled connection to every configured URL before real traffic.
+    ///
+    /// Each URL receives a single cheap request, paying the TCP and TLS setup up front.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the URLs cannot be reached.
+    pub async fn warm_up(&self) -> Result<(), SchemaRegistryError> {
+        let calls = self.urls.iter().map(|url| {
+            let http = self.http.clone();
+            let send = self.send.clone();
+            let url = format!("{}/config", url);
+
+            async move {
+                let response = http
+                    .get(&url)
+                    .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .send_with(&send)
+                    .await?;
+
+                parse_response::<serde::de::IgnoredAny>(response).await
+            }
+        });
+
+        futures::future::try_join_all(calls).await?;
+
+        Ok(())
+    }
+
+    /// Run calls on a handle recording the URLs they are sent to and how each attempt went.
+    ///
+    /// The report is returned alongside a successful result. On failure, the error is a
+    /// [`SchemaRegistryError::Failover`] exposing it through
+    /// [`SchemaRegistryError::failover_report`].
+    pub async fn with_failover_report<T, F, Fut>(
+        &self,
+        f: F,
+    ) -> Result<(T, FailoverReport), SchemaRegistryError>
+    where
+        F: FnOnce(SchemaRegistryClient) -> Fut,
+        Fut: Future<Output = Result<T, SchemaRegistryError>>,
+    {
+        let collector = Arc::new(ReportCollector::default());
+        let reporting = Self {
+            report: Some(collector.clone()),
+            ..self.clone()
+        };
+
+        match f(reporting).await {
+            Ok(result) => Ok((result, collector.take())),
+            Err(source) => Err(SchemaRegistryError::Failover {
+                report: collector.take(),
+                source: Box::new(source),
+            }),
+        }
+    }
+
+    /// Execute the calls to each URL concurrently and return the first successful result.
+    ///
+    /// At most `max_failover_attempts` URLs are called. With URL health tracking enabled,
+    /// URLs that recently failed are only called once every other URL failed.
+    async fn exec_calls<'a, T: Send + 'a>(
+        &'a self,
+        calls: Vec<(&'a str, BoxFuture<'a, Result<T, SchemaRegistryError>>)>,
+    ) -> Result<T, SchemaRegistryError> {
+        self.dispatch_calls(calls, false).await
+    }
+
+    /// Execute the calls to each URL one after the other, stopping at the first success.
+    ///
+    /// Used by mutating calls so a write is not sent to every URL at once. Only an unavailable
+    /// registry moves the call on to the next URL, client errors such as a 409 are returned as is.
+    async fn exec_calls_failover<'a, T: Send + 'a>(
+        &'a self,
+        calls: Vec<(&'a str, BoxFuture<'a, Result<T, SchemaRegistryError>>)>,
+    ) -> Result<T, SchemaRegistryError> {
+        self.dispatch_calls(calls, true).await
+    }
+
+    async fn dispatch_calls<'a, T: Send + 'a>(
+        &'a self,
+        mut calls: Vec<(&'a str, BoxFuture<'a, Result<T, SchemaRegistryError>>)>,
+        sequential: bool,
+    ) -> Result<T, SchemaRegistryError> {
+        if self.endpoint_strategy == EndpointStrategy::RoundRobin && !calls.is_empty() {
+            let start = self.next_url.fetch_add(1, Ordering::Relaxed) % calls.len();
+            calls.rotate_left(start);
+        }
+
+        let sequential = sequential || self.endpoint_strategy != EndpointStrategy::Broadcast;
+
+        let run = |calls| async move {
+            if sequential {
+                exec_calls_failover(calls).await
+            } else {
+                exec_calls(calls).await
+            }
+        };
+
+        let calls = match self.report.as_deref() {
+            Some(report) => report.track(calls),
+            None => calls,
+        };
+
+        let Some(health) = self.health.as_deref() else {
+            let calls = calls
+                .into_iter()
+                .take(self.max_failover_attempts)
+                .map(|(_, call)| call)
+                .collect();
+
+            return run(calls).await;
+        };
+
+        let (mut cooling_down, mut preferred): (Vec<_>, Vec<_>) = calls
+            .into_iter()
+            .partition(|(url, _)| health.is_cooling_down(url));
+
+        preferred.truncate(self.max_failover_attempts);
+        cooling_down.truncate(self.max_failover_attempts - preferred.len());
+
+        if preferred.is_empty() {
+            return run(health.track(cooling_down)).await;
+        }
+
+        match run(health.track(preferred)).await {
+            Err(e) if e.is_unavailable() && !cooling_down.is_empty() => {
+                run(health.track(cooling_down)).await
+            }
+            result => result,
+        }
+    }
+
+    fn check_permanent_delete(
+        &self,
+        subject: &str,
+        permanent: bool,
+    ) -> Result<(), SchemaRegistryError> {
+        if permanent && !self.allow_permanent_delete {
+            return Err(SchemaRegistryError::PermanentDeleteDisabled {
+                subject: subject.to_owned(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait::async_trait]
+impl SchemaRegistryAPI for SchemaRegistryClient {
+    async fn is_compatible(
+        &self,
+        subject: &str,
+        version: Version,
+        schema: &UnregisteredSchema,
+    ) -> Result<bool, SchemaRegistryError> {
+        let mut http_calls = Vec::with_capacity(self.urls.len());
+
+        for base_url in self.urls.iter() {
+            let http = self.http.clone();
+            let send = self.send.clone();
+            let content_type = self.content_type;
+            let url = format!(
+                "{}/compatibility/subjects/{}/versions/{}",
+                base_url, subject, version
+            );
+
+            let call = async move {
+                let response = http
+                    .post(&url)
+                    .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .header(header::CONTENT_TYPE, content_type)
+                    .json(schema)
+                    .send_with(&send)
+                    .await?;
+
+                parse_response::<CompatibilityCheck>(response).await
+            }
+            .boxed();
+
+            http_calls.push((base_url.as_str(), call));
+        }
+
+        let result = self.exec_calls(http_calls).await?;
+
+        Ok(result.is_compatible)
+    }
+
+    async fn is_compatible_verbose(
+        &self,
+        subject: &str,
+        version: Version,
+        schema: &UnregisteredSchema,
+    ) -> Result<CompatibilityResult, SchemaRegistryError> {
+        let mut http_calls = Vec::with_capacity(self.urls.len());
+
+        for base_url in self.urls.iter() {
+            let http = self.http.clone();
+            let send = self.send.clone();
+            let content_type = self.content_type;
+            let url = format!(
+                "{}/compatibility/subjects/{}/versions/{}?verbose=true",
+                base_url, subject, version
+            );
+
+            let call = async move {
+                let response = http
+                    .post(&url)
+                    .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .header(header::CONTENT_TYPE, content_type)
+                    .json(schema)
+                    .send_with(&send)
+                    .await?;
+
+                parse_response::<CompatibilityResult>(response).await
+            }
+            .boxed();
+
+            http_calls.push((base_url.as_str(), call));
+        }
+
+        let result = self.exec_calls(http_calls).await?;
+
+        Ok(result)
+    }
+
+    async fn is_fully_compatible(
+        &self,
+        subject: &str,
+        schema: &UnregisteredSchema,
+    ) -> Result<bool, SchemaRegistryError> {
+        let mut http_calls = Vec::with_capacity(self.urls.len());
+
+        for base_url in self.urls.iter() {
+            let http = self.http.clone();
+            let send = self.send.clone();
+            let content_type = self.content_type;
+            let url = format!("{}/compatibility/subjects/{}/versions", base_url, subject);
+
+            let call = async move {
+                let response = http
+                    .post(&url)
+                    .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .header(header::CONTENT_TYPE, content_type)
+                    .json(schema)
+                    .send_with(&send)
+                    .await?;
+
+                parse_response::<CompatibilityCheck>(response).await
+            }
+            .boxed();
+
+            http_calls.push((base_url.as_str(), call));
+        }
+
+        let result = self.exec_calls(http_calls).await?;
+
+        Ok(result.is_compatible)
+    }
+
+    async fn get_configuration(&self) -> Result<ClusterConfig, SchemaRegistryError> {
+        let mut http_calls = Vec::with_capacity(self.urls.len());
+
+        for base_url in self.urls.iter() {
+            let http = self.http.clone();
+            let send = self.send.clone();
+            let url = format!("{}/config", base_url);
+
+            let call = async move {
+                let response = http
+                    .get(&url)
+                    .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .send_with(&send)
+                    .await?;
+
+                parse_response::<ClusterConfig>(response).await
+            }
+            .boxed();
+
+            http_calls.push((base_url.as_str(), call));
+        }
+
+        let result = self.exec_calls(http_calls).await?;
+
+        Ok(result)
+    }
+
+    async fn get_configuration_raw(&self) -> Result<serde_json::Value, SchemaRegistryError> {
+        let mut http_calls = Vec::with_capacity(self.urls.len());
+
+        for base_url in self.urls.iter() {
+            let http = self.http.clone();
+            let send = self.send.clone();
+            let url = format!("{}/config", base_url);
+
+            let call = async move {
+                let response = http
+                    .get(&url)
+                    .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .send_with(&send)
+                    .await?;
+
+                parse_response::<serde_json::Value>(response).await
+            }
+            .boxed();
+
+            http_calls.push((base_url.as_str(), call));
+        }
+
+        let result = self.exec_calls(http_calls).await?;
+
+        Ok(result)
+    }
+
+    async fn update_configuration(
+        &self,
+        configuration: &ClusterConfig,
+    ) -> Result<ClusterConfig, SchemaRegistryError> {
+        let mut http_calls = Vec::with_capacity(self.urls.len());
+
+        for base_url in self.urls.iter() {
+            let http = self.http.clone();
+            let send = self.send.clone();
+            let content_type = self.content_type;
+            let url = format!("{}/config", base_url);
+
+            let call = async move {
+                let response = http
+                    .put(&url)
+                    .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .header(header::CONTENT_TYPE, content_type)
+                    .json(configuration)
+                    .send_with(&send)
+                    .await?;
+
+                parse_response::<ClusterConfig>(response).await
+            }
+            .boxed();
+
+            http_calls.push((base_url.as_str(), call));
+        }
+
+        let result = self.exec_calls_failover(http_calls).await?;
+
+        Ok(result)
+    }
+
+    async fn delete_configuration(&self) -> Result<ClusterConfig, SchemaRegistryError> {
+        let mut http_calls = Vec::with_capacity(self.urls.len());
+
+        for base_url in self.urls.iter() {
+            let http = self.http.clone();
+            let send = self.send.clone();
+            let url = format!("{}/config", base_url);
+
+            let call = async move {
+                let response = http
+                    .delete(&url)
+                    .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .send_with(&send)
+                    .await?;
+
+                parse_response::<ClusterConfig>(response).await
+            }
+            .boxed();
+
+            http_calls.push((base_url.as_str(), call));
+        }
+
+        let result = self.exec_calls_failover(http_calls).await?;
+
+        Ok(result)
+    }
+
+    async fn get_subject_configuration(
+        &self,
+        subject: &str,
+    ) -> Result<SubjectConfig, SchemaRegistryError> {
+        let mut http_calls = Vec::with_capacity(self.urls.len());
+
+        for base_url in self.urls.iter() {
+            let http = self.http.clone();
+            let send = self.send.clone();
+            let url = format!("{}/config/{}", base_url, subject);
+
+            let call = async move {
+                let response = http
+                    .get(&url)
+                    .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .send_with(&send)
+                    .await?;
+
+                parse_response::<SubjectConfig>(response).await
+            }
+            .boxed();
+
+            http_calls.push((base_url.as_str(), call));
+        }
+
+        let result = self.exec_calls(http_calls).await?;
+
+        Ok(result)
+    }
+
+    async fn get_subject_configuration_with_default(
+        &self,
+        subject: &str,
+        default_to_global: bool,
+    ) -> Result<SubjectConfig, SchemaRegistryError> {
+        let mut http_calls = Vec::with_capacity(self.urls.len());
+
+        for base_url in self.urls.iter() {
+            let http = self.http.clone();
+            let send = self.send.clone();
+            let url = format!(
+                "{}/config/{}?defaultToGlobal={}",
+                base_url, subject, default_to_global
+            );
+
+            let call = async move {
+                let response = http
+                    .get(&url)
+                    .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .send_with(&send)
+                    .await?;
+
+                parse_response::<SubjectConfig>(response).await
+            }
+            .boxed();
+
+            http_calls.push((base_url.as_str(), call));
+        }
+
+        let result = self.exec_calls(http_calls).await?;
+
+        Ok(result)
+    }
+
+    async fn get_subject_configuration_raw(
+        &self,
+        subject: &str,
+    ) -> Result<serde_json::Value, SchemaRegistryError> {
+        let mut http_calls = Vec::with_capacity(self.urls.len());
+
+        for base_url in self.urls.iter() {
+            let http = self.http.clone();
+            let send = self.send.clone();
+            let url = format!("{}/config/{}", base_url, subject);
+
+            let call = async move {
+                let response = http
+                    .get(&url)
+                    .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .send_with(&send)
+                    .await?;
+
+                parse_response::<serde_json::Value>(response).await
+            }
+            .boxed();
+
+            http_calls.push((base_url.as_str(), call));
+        }
+
+        let result = self.exec_calls(http_calls).await?;
+
+        Ok(result)
+    }
+
+    async fn update_subject_configuration(
+        &self,
+        subject: &str,
+        configuration: &SubjectConfig,
+    ) -> Result<SubjectConfig, SchemaRegistryError> {
+        let mut http_calls = Vec::with_capacity(self.urls.len());
+
+        for base_url in self.urls.iter() {
+            let http = self.http.clone();
+            let send = self.send.clone();
+            let content_type = self.content_type;
+            let url = format!("{}/config/{}", base_url, subject);
+
+            let call = async move {
+                let response = http
+                    .put(&url)
+                    .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .header(header::CONTENT_TYPE, content_type)
+                    .json(configuration)
+                    .send_with(&send)
+                    .await?;
 
-        Ok(Self { http, urls })
-    }
+                parse_response::<SubjectConfig>(response).await
+            }
+            .boxed();
 
-    /// Create a new `SchemaRegistryClient` from a `SchemaRegistryConfig`.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the `SchemaRegistryConfig` is invalid or if the HTTP client cannot be created.
-    pub fn from_conf(conf: SchemaRegistryConfig) -> Result<Self, SchemaRegistryError> {
-        let urls = Arc::from(conf.urls.clone());
-        let http = config::build_http_client(&conf)?;
+            http_calls.push((base_url.as_str(), call));
+        }
 
-        Ok(Self { http, urls })
+        let result = self.exec_calls_failover(http_calls).await?;
+
+        Ok(result)
     }
-}
 
-#[cfg_attr(test, mockall::automock)]
-#[async_trait::async_trait]
-impl SchemaRegistryAPI for SchemaRegistryClient {
-    async fn is_compatible(
+    async fn delete_subject_configuration(
         &self,
         subject: &str,
-        version: Version,
-        schema: &UnregisteredSchema,
-    ) -> Result<bool, SchemaRegistryError> {
+    ) -> Result<SubjectConfig, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!(
-                "{}/compatibility/subjects/{}/versions/{}",
-                url, subject, version
-            );
+            let send = self.send.clone();
+            let url = format!("{}/config/{}", base_url, subject);
 
             let call = async move {
                 let response = http
-                    .post(&url)
+                    .delete(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .json(schema)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
-                parse_response::<CompatibilityCheck>(response).await
+                parse_response::<SubjectConfig>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls_failover(http_calls).await?;
 
-        Ok(result.is_compatible)
+        Ok(result)
     }
 
-    async fn is_fully_compatible(
-        &self,
-        subject: &str,
-        schema: &UnregisteredSchema,
-    ) -> Result<bool, SchemaRegistryError> {
+    async fn get_exporters(&self) -> Result<Vec<String>, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/compatibility/subjects/{}/versions", url, subject);
+            let send = self.send.clone();
+            let url = format!("{}/exporters", base_url);
 
             let call = async move {
                 let response = http
-                    .post(&url)
+                    .get(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .json(schema)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
-                parse_response::<CompatibilityCheck>(response).await
+                parse_response::<Vec<String>>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls(http_calls).await?;
 
-        Ok(result.is_compatible)
+        Ok(result)
     }
 
-    async fn get_configuration(&self) -> Result<ClusterConfig, SchemaRegistryError> {
+    async fn get_contexts(&self) -> Result<Vec<String>, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/config", url);
+            let send = self.send.clone();
+            let url = format!("{}/contexts", base_url);
 
             let call = async move {
                 let response = http
                     .get(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
-                parse_response::<ClusterConfig>(response).await
+                parse_response::<Vec<String>>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls(http_calls).await?;
 
         Ok(result)
     }
 
-    async fn update_configuration(
-        &self,
-        configuration: &ClusterConfig,
-    ) -> Result<ClusterConfig, SchemaRegistryError> {
+    async fn ping(&self) -> Result<(), SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/config", url);
+            let send = self.send.clone();
+            let url = format!("{}/config", base_url);
 
             let call = async move {
                 let response = http
-                    .put(&url)
+                    .get(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .json(configuration)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
-                parse_response::<ClusterConfig>(response).await
+                check_response(response).await?;
+
+                Ok(())
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
-
-        Ok(result)
+        self.exec_calls(http_calls).await
     }
 
-    async fn get_subject_configuration(
-        &self,
-        subject: &str,
-    ) -> Result<SubjectConfig, SchemaRegistryError> {
+    async fn get_cluster_id(&self) -> Result<ClusterId, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/config/{}", url, subject);
+            let send = self.send.clone();
+            let url = format!("{}/v1/metadata/id", base_url);
 
             let call = async move {
                 let response = http
                     .get(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
-                parse_response::<SubjectConfig>(response).await
+                parse_response::<ClusterId>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls(http_calls).await?;
 
         Ok(result)
     }
 
-    async fn update_subject_configuration(
-        &self,
-        subject: &str,
-        configuration: &SubjectConfig,
-    ) -> Result<SubjectConfig, SchemaRegistryError> {
+    async fn get_server_version(&self) -> Result<ServerVersion, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/config/{}", url, subject);
+            let send = self.send.clone();
+            let url = format!("{}/v1/metadata/version", base_url);
 
             let call = async move {
                 let response = http
-                    .put(&url)
+                    .get(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .json(configuration)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
-                parse_response::<SubjectConfig>(response).await
+                parse_response::<ServerVersion>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls(http_calls).await?;
 
         Ok(result)
     }
 
-    async fn get_exporters(&self) -> Result<Vec<String>, SchemaRegistryError> {
+    async fn detect_flavor(&self) -> Result<RegistryFlavor, SchemaRegistryError> {
+        if self.urls.iter().any(|url| url.contains("/apis/ccompat/")) {
+            return Ok(RegistryFlavor::Apicurio);
+        }
+
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/exporters", url);
+            let send = self.send.clone();
+            let url = format!("{}/", base_url);
 
             let call = async move {
                 let response = http
                     .get(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
-                parse_response::<Vec<String>>(response).await
+                let server = response
+                    .headers()
+                    .get(header::SERVER)
+                    .and_then(|server| server.to_str().ok())
+                    .map(str::to_ascii_lowercase);
+
+                check_response(response).await?;
+
+                Ok(server)
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let server = match self.exec_calls(http_calls).await {
+            Ok(server) => server,
+            Err(e) if !e.is_unavailable() => None,
+            Err(e) => return Err(e),
+        };
 
-        Ok(result)
-    }
+        match server.as_deref() {
+            Some(server) if server.contains("seastar") => return Ok(RegistryFlavor::Redpanda),
+            Some(server) if server.contains("apicurio") => return Ok(RegistryFlavor::Apicurio),
+            _ => {}
+        }
 
-    async fn get_contexts(&self) -> Result<Vec<String>, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/contexts", url);
+            let send = self.send.clone();
+            let url = format!("{}/v1/metadata/id", base_url);
 
             let call = async move {
                 let response = http
                     .get(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
-                parse_response::<Vec<String>>(response).await
+                parse_response::<serde::de::IgnoredAny>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        match self.exec_calls(http_calls).await {
+            Ok(_) => Ok(RegistryFlavor::Confluent),
+            Err(e) if !e.is_unavailable() => Ok(RegistryFlavor::Unknown),
+            Err(e) => Err(e),
+        }
+    }
 
-        Ok(result)
+    async fn get_subjects_in_context(
+        &self,
+        context: &str,
+        deleted: bool,
+    ) -> Result<Vec<String>, SchemaRegistryError> {
+        let prefix = format!(":.{}:", context.trim_start_matches('.'));
+        let result = self
+            .get_subjects_filtered(None, Some(context), deleted)
+            .await?;
+
+        let subjects = result
+            .into_iter()
+            .map(|subject| match subject.strip_prefix(&prefix) {
+                Some(unqualified) => unqualified.to_owned(),
+                None => subject,
+            })
+            .collect();
+
+        Ok(subjects)
     }
 
     async fn create_exporter(
@@ -294,26 +1093,29 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     ) -> Result<String, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/exporters", url);
+            let send = self.send.clone();
+            let content_type = self.content_type;
+            let url = format!("{}/exporters", base_url);
 
             let call = async move {
                 let response = http
                     .post(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .header(header::CONTENT_TYPE, content_type)
                     .json(config)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<String>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls_failover(http_calls).await?;
 
         Ok(result)
     }
@@ -325,26 +1127,29 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     ) -> Result<String, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/exporters/{}", url, name);
+            let send = self.send.clone();
+            let content_type = self.content_type;
+            let url = format!("{}/exporters/{}", base_url, name);
 
             let call = async move {
                 let response = http
                     .put(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .header(header::CONTENT_TYPE, content_type)
                     .json(config)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<String>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls_failover(http_calls).await?;
 
         Ok(result)
     }
@@ -356,26 +1161,29 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     ) -> Result<String, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/exporters/{}/config", url, name);
+            let send = self.send.clone();
+            let content_type = self.content_type;
+            let url = format!("{}/exporters/{}/config", base_url, name);
 
             let call = async move {
                 let response = http
                     .put(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .header(header::CONTENT_TYPE, content_type)
                     .json(config)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<String>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls_failover(http_calls).await?;
 
         Ok(result)
     }
@@ -383,25 +1191,26 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     async fn get_exporter(&self, name: &str) -> Result<ExporterConfig, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/exporters/{}", url, name);
+            let send = self.send.clone();
+            let url = format!("{}/exporters/{}", base_url, name);
 
             let call = async move {
                 let response = http
                     .get(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<ExporterConfig>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls(http_calls).await?;
 
         Ok(result)
     }
@@ -412,25 +1221,26 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     ) -> Result<HashMap<String, String>, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/exporters/{}/config", url, name);
+            let send = self.send.clone();
+            let url = format!("{}/exporters/{}/config", base_url, name);
 
             let call = async move {
                 let response = http
                     .get(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<HashMap<String, String>>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls(http_calls).await?;
 
         Ok(result)
     }
@@ -438,25 +1248,26 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     async fn get_exporter_status(&self, name: &str) -> Result<ExporterStatus, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/exporters/{}/status", url, name);
+            let send = self.send.clone();
+            let url = format!("{}/exporters/{}/status", base_url, name);
 
             let call = async move {
                 let response = http
                     .get(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<ExporterStatus>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls(http_calls).await?;
 
         Ok(result)
     }
@@ -464,25 +1275,26 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     async fn pause_exporter(&self, name: &str) -> Result<(), SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/exporters/{}/pause", url, name);
+            let send = self.send.clone();
+            let url = format!("{}/exporters/{}/pause", base_url, name);
 
             let call = async move {
                 let response = http
                     .put(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<()>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        exec_calls(http_calls).await?;
+        self.exec_calls_failover(http_calls).await?;
 
         Ok(())
     }
@@ -490,25 +1302,26 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     async fn reset_exporter(&self, name: &str) -> Result<(), SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/exporters/{}/reset", url, name);
+            let send = self.send.clone();
+            let url = format!("{}/exporters/{}/reset", base_url, name);
 
             let call = async move {
                 let response = http
                     .put(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<()>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        exec_calls(http_calls).await?;
+        self.exec_calls_failover(http_calls).await?;
 
         Ok(())
     }
@@ -516,25 +1329,26 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     async fn resume_exporter(&self, name: &str) -> Result<(), SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/exporters/{}/resume", url, name);
+            let send = self.send.clone();
+            let url = format!("{}/exporters/{}/resume", base_url, name);
 
             let call = async move {
                 let response = http
                     .put(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<()>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        exec_calls(http_calls).await?;
+        self.exec_calls_failover(http_calls).await?;
 
         Ok(())
     }
@@ -542,25 +1356,26 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     async fn delete_exporter(&self, name: &str) -> Result<(), SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/exporters/{}", url, name);
+            let send = self.send.clone();
+            let url = format!("{}/exporters/{}", base_url, name);
 
             let call = async move {
                 let response = http
                     .delete(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<()>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        exec_calls(http_calls).await?;
+        self.exec_calls_failover(http_calls).await?;
 
         Ok(())
     }
@@ -568,25 +1383,26 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     async fn get_global_resource_mode(&self) -> Result<Mode, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/mode", url);
+            let send = self.send.clone();
+            let url = format!("{}/mode", base_url);
 
             let call = async move {
                 let response = http
                     .get(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<ResourceMode>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls(http_calls).await?;
 
         Ok(result.mode)
     }
@@ -600,26 +1416,29 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
 
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/mode?force={}", url, force);
+            let send = self.send.clone();
+            let content_type = self.content_type;
+            let url = format!("{}/mode?force={}", base_url, force);
 
             let call = async move {
                 let response = http
                     .put(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .header(header::CONTENT_TYPE, content_type)
                     .json(&body)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<ResourceMode>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls_failover(http_calls).await?;
 
         Ok(result.mode)
     }
@@ -627,25 +1446,60 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     async fn get_subject_resource_mode(&self, subject: &str) -> Result<Mode, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
+            let http = self.http.clone();
+            let send = self.send.clone();
+            let url = format!("{}/mode/{}", base_url, subject);
+
+            let call = async move {
+                let response = http
+                    .get(&url)
+                    .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .send_with(&send)
+                    .await?;
+
+                parse_response::<ResourceMode>(response).await
+            }
+            .boxed();
+
+            http_calls.push((base_url.as_str(), call));
+        }
+
+        let result = self.exec_calls(http_calls).await?;
+
+        Ok(result.mode)
+    }
+
+    async fn get_subject_resource_mode_with_default(
+        &self,
+        subject: &str,
+        default_to_global: bool,
+    ) -> Result<Mode, SchemaRegistryError> {
+        let mut http_calls = Vec::with_capacity(self.urls.len());
+
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/mode/{}", url, subject);
+            let send = self.send.clone();
+            let url = format!(
+                "{}/mode/{}?defaultToGlobal={}",
+                base_url, subject, default_to_global
+            );
 
             let call = async move {
                 let response = http
                     .get(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<ResourceMode>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls(http_calls).await?;
 
         Ok(result.mode)
     }
@@ -660,78 +1514,88 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
 
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/mode/{}?force={}", url, subject, force);
+            let send = self.send.clone();
+            let content_type = self.content_type;
+            let url = format!("{}/mode/{}?force={}", base_url, subject, force);
 
             let call = async move {
                 let response = http
                     .put(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .header(header::CONTENT_TYPE, content_type)
                     .json(&body)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<ResourceMode>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls_failover(http_calls).await?;
 
         Ok(result.mode)
     }
 
-    async fn delete_subject_mode(&self, subject: &str) -> Result<Mode, SchemaRegistryError> {
+    async fn delete_subject_mode(
+        &self,
+        subject: &str,
+    ) -> Result<Option<Mode>, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/mode/{}", url, subject);
+            let send = self.send.clone();
+            let url = format!("{}/mode/{}", base_url, subject);
 
             let call = async move {
                 let response = http
                     .delete(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<ResourceMode>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
-
-        Ok(result.mode)
+        match self.exec_calls_failover(http_calls).await {
+            Ok(result) => Ok(Some(result.mode)),
+            Err(e) if e.is_subject_not_found() => Ok(None),
+            Err(e) => Err(e),
+        }
     }
 
     async fn get_schema_by_id(&self, id: u32) -> Result<Schema, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/schemas/ids/{}", url, id);
+            let send = self.send.clone();
+            let url = format!("{}/schemas/ids/{}", base_url, id);
 
             let call = async move {
                 let response = http
                     .get(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<Schema>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls(http_calls).await?;
 
         Ok(result)
     }
@@ -739,25 +1603,28 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     async fn get_schema_by_id_raw(&self, id: u32) -> Result<StringSchema, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/schemas/ids/{}/schema", url, id);
+            let send = self.send.clone();
+            let url = format!("{}/schemas/ids/{}/schema", base_url, id);
 
             let call = async move {
                 let response = http
                     .get(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
-                parse_response::<StringSchema>(response).await
+                parse_text_response(response)
+                    .await
+                    .map(|schema| StringSchema(schema.into()))
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls(http_calls).await?;
 
         Ok(result)
     }
@@ -765,25 +1632,26 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     async fn get_schemas_types(&self) -> Result<Vec<SchemaType>, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/schemas/types", url);
+            let send = self.send.clone();
+            let url = format!("{}/schemas/types", base_url);
 
             let call = async move {
                 let response = http
                     .get(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<Vec<SchemaType>>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls(http_calls).await?;
 
         Ok(result)
     }
@@ -794,25 +1662,26 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     ) -> Result<Vec<SubjectVersion>, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/schemas/ids/{}/versions", url, id);
+            let send = self.send.clone();
+            let url = format!("{}/schemas/ids/{}/versions", base_url, id);
 
             let call = async move {
                 let response = http
                     .get(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<Vec<SubjectVersion>>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls(http_calls).await?;
 
         Ok(result)
     }
@@ -820,25 +1689,73 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     async fn get_subjects(&self, deleted: bool) -> Result<Vec<String>, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/subjects?deleted={}", url, deleted);
+            let send = self.send.clone();
+            let url = format!("{}/subjects?deleted={}", base_url, deleted);
 
             let call = async move {
                 let response = http
                     .get(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<Vec<String>>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
+        }
+
+        let result = self.exec_calls(http_calls).await?;
+
+        Ok(result)
+    }
+
+    async fn get_subjects_filtered<'a>(
+        &self,
+        prefix: Option<&'a str>,
+        context: Option<&'a str>,
+        deleted: bool,
+    ) -> Result<Vec<String>, SchemaRegistryError> {
+        let subject_prefix = match (context, prefix) {
+            (Some(context), prefix) => Some(format!(
+                ":.{}:{}",
+                context.trim_start_matches('.'),
+                prefix.unwrap_or_default()
+            )),
+            (None, prefix) => prefix.map(str::to_owned),
+        };
+
+        let mut http_calls = Vec::with_capacity(self.urls.len());
+
+        for base_url in self.urls.iter() {
+            let http = self.http.clone();
+            let send = self.send.clone();
+            let subject_prefix = subject_prefix.clone();
+            let url = format!("{}/subjects", base_url);
+
+            let call = async move {
+                let mut request = http
+                    .get(&url)
+                    .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .query(&[("deleted", deleted)]);
+
+                if let Some(subject_prefix) = &subject_prefix {
+                    request = request.query(&[("subjectPrefix", subject_prefix)]);
+                }
+
+                let response = request.send_with(&send).await?;
+
+                parse_response::<Vec<String>>(response).await
+            }
+            .boxed();
+
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls(http_calls).await?;
 
         Ok(result)
     }
@@ -846,25 +1763,61 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     async fn get_subject_versions(&self, subject: &str) -> Result<Vec<u32>, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
+            let http = self.http.clone();
+            let send = self.send.clone();
+            let url = format!("{}/subjects/{}/versions", base_url, subject);
+
+            let call = async move {
+                let response = http
+                    .get(&url)
+                    .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .send_with(&send)
+                    .await?;
+
+                parse_response::<Vec<u32>>(response).await
+            }
+            .boxed();
+
+            http_calls.push((base_url.as_str(), call));
+        }
+
+        let result = self.exec_calls(http_calls).await?;
+
+        Ok(result)
+    }
+
+    async fn get_subject_versions_with_deleted(
+        &self,
+        subject: &str,
+        deleted: bool,
+    ) -> Result<Vec<u32>, SchemaRegistryError> {
+        let mut http_calls = Vec::with_capacity(self.urls.len());
+
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/subjects/{}/versions", url, subject);
+            let send = self.send.clone();
+            let mut url = format!("{}/subjects/{}/versions", base_url, subject);
+
+            if deleted {
+                url.push_str("?deleted=true");
+            }
 
             let call = async move {
                 let response = http
                     .get(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<Vec<u32>>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls(http_calls).await?;
 
         Ok(result)
     }
@@ -874,27 +1827,67 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
         subject: &str,
         permanent: bool,
     ) -> Result<Vec<u32>, SchemaRegistryError> {
+        self.check_permanent_delete(subject, permanent)?;
+
+        let mut http_calls = Vec::with_capacity(self.urls.len());
+
+        for base_url in self.urls.iter() {
+            let http = self.http.clone();
+            let send = self.send.clone();
+            let url = format!("{}/subjects/{}?permanent={}", base_url, subject, permanent);
+
+            let call = async move {
+                let response = http
+                    .delete(&url)
+                    .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .send_with(&send)
+                    .await?;
+
+                parse_response::<Vec<u32>>(response).await
+            }
+            .boxed();
+
+            http_calls.push((base_url.as_str(), call));
+        }
+
+        let result = self.exec_calls_failover(http_calls).await?;
+
+        Ok(result)
+    }
+
+    async fn delete_subject_forced(
+        &self,
+        subject: &str,
+        permanent: bool,
+        force: bool,
+    ) -> Result<Vec<u32>, SchemaRegistryError> {
+        self.check_permanent_delete(subject, permanent)?;
+
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/subjects/{}?permanent={}", url, subject, permanent);
+            let send = self.send.clone();
+            let url = format!(
+                "{}/subjects/{}?permanent={}&force={}",
+                base_url, subject, permanent, force
+            );
 
             let call = async move {
                 let response = http
                     .delete(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<Vec<u32>>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls_failover(http_calls).await?;
 
         Ok(result)
     }
@@ -906,25 +1899,62 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     ) -> Result<Subject, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
+            let http = self.http.clone();
+            let send = self.send.clone();
+            let url = format!("{}/subjects/{}/versions/{}", base_url, subject, version);
+
+            let call = async move {
+                let response = http
+                    .get(&url)
+                    .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .send_with(&send)
+                    .await?;
+
+                parse_response::<Subject>(response).await
+            }
+            .boxed();
+
+            http_calls.push((base_url.as_str(), call));
+        }
+
+        let result = self.exec_calls(http_calls).await?;
+
+        Ok(result)
+    }
+
+    async fn get_subject_version_opt(
+        &self,
+        subject: &str,
+        version: Version,
+        deleted: bool,
+    ) -> Result<Subject, SchemaRegistryError> {
+        let mut http_calls = Vec::with_capacity(self.urls.len());
+
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/subjects/{}/versions/{}", url, subject, version);
+            let send = self.send.clone();
+            let mut url = format!("{}/subjects/{}/versions/{}", base_url, subject, version);
+
+            if deleted {
+                url.push_str("?deleted=true");
+            }
 
             let call = async move {
                 let response = http
                     .get(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<Subject>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls(http_calls).await?;
 
         Ok(result)
     }
@@ -936,25 +1966,31 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     ) -> Result<StringSchema, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/subjects/{}/versions/{}/schema", url, subject, version);
+            let send = self.send.clone();
+            let url = format!(
+                "{}/subjects/{}/versions/{}/schema",
+                base_url, subject, version
+            );
 
             let call = async move {
                 let response = http
                     .get(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
-                parse_response::<StringSchema>(response).await
+                parse_text_response(response)
+                    .await
+                    .map(|schema| StringSchema(schema.into()))
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls(http_calls).await?;
 
         Ok(result)
     }
@@ -967,30 +2003,90 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     ) -> Result<u32, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/subjects/{}/versions?={}", url, subject, normalize);
+            let send = self.send.clone();
+            let content_type = self.content_type;
+            let url = format!(
+                "{}/subjects/{}/versions?normalize={}",
+                base_url, subject, normalize
+            );
 
             let call = async move {
                 let response = http
                     .post(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .header(header::CONTENT_TYPE, content_type)
                     .json(schema)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<Id>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls_failover(http_calls).await?;
 
         Ok(result.id)
     }
 
+    async fn register_subject_schema_full(
+        &self,
+        subject: &str,
+        schema: &UnregisteredSchema,
+        normalize: bool,
+    ) -> Result<Subject, SchemaRegistryError> {
+        let mut http_calls = Vec::with_capacity(self.urls.len());
+
+        for base_url in self.urls.iter() {
+            let http = self.http.clone();
+            let send = self.send.clone();
+            let content_type = self.content_type;
+            let url = format!(
+                "{}/subjects/{}/versions?normalize={}",
+                base_url, subject, normalize
+            );
+
+            let call = async move {
+                let response = http
+                    .post(&url)
+                    .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .header(header::CONTENT_TYPE, content_type)
+                    .json(schema)
+                    .send_with(&send)
+                    .await?;
+
+                parse_response::<RegisteredSchema>(response).await
+            }
+            .boxed();
+
+            http_calls.push((base_url.as_str(), call));
+        }
+
+        let registered = self.exec_calls_failover(http_calls).await?;
+
+        let Some(version) = registered.version else {
+            return self.lookup_subject_schema(subject, schema, normalize).await;
+        };
+
+        Ok(Subject {
+            id: registered.id,
+            subject: registered.subject.unwrap_or_else(|| subject.to_owned()),
+            version,
+            schema_type: schema.schema_type.clone(),
+            schema: registered
+                .schema
+                .unwrap_or_else(|| schema.schema.clone())
+                .into(),
+            references: schema.references.clone(),
+            metadata: None,
+            rule_set: None,
+        })
+    }
+
     async fn lookup_subject_schema(
         &self,
         subject: &str,
@@ -999,26 +2095,29 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     ) -> Result<Subject, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
-            let url = format!("{}/subjects/{}?normalize={}", url, subject, normalize);
+            let send = self.send.clone();
+            let content_type = self.content_type;
+            let url = format!("{}/subjects/{}?normalize={}", base_url, subject, normalize);
 
             let call = async move {
                 let response = http
                     .post(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
+                    .header(header::CONTENT_TYPE, content_type)
                     .json(schema)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<Subject>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls(http_calls).await?;
 
         Ok(result)
     }
@@ -1029,30 +2128,33 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
         version: Version,
         permanent: bool,
     ) -> Result<u32, SchemaRegistryError> {
+        self.check_permanent_delete(subject, permanent)?;
+
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
+            let send = self.send.clone();
             let url = format!(
                 "{}/subjects/{}/versions/{}?permanent={}",
-                url, subject, version, permanent
+                base_url, subject, version, permanent
             );
 
             let call = async move {
                 let response = http
                     .delete(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<u32>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls_failover(http_calls).await?;
 
         Ok(result)
     }
@@ -1064,28 +2166,29 @@ impl SchemaRegistryAPI for SchemaRegistryClient {
     ) -> Result<Vec<u32>, SchemaRegistryError> {
         let mut http_calls = Vec::with_capacity(self.urls.len());
 
-        for url in self.urls.iter() {
+        for base_url in self.urls.iter() {
             let http = self.http.clone();
+            let send = self.send.clone();
             let url = format!(
                 "{}/subjects/{}/versions/{}/referencedBy",
-                url, subject, version
+                base_url, subject, version
             );
 
             let call = async move {
                 let response = http
                     .get(&url)
                     .header(header::ACCEPT, VND_SCHEMA_REGISTRY_V1_JSON)
-                    .send()
+                    .send_with(&send)
                     .await?;
 
                 parse_response::<Vec<u32>>(response).await
             }
             .boxed();
 
-            http_calls.push(call);
+            http_calls.push((base_url.as_str(), call));
         }
 
-        let result = exec_calls(http_calls).await?;
+        let result = self.exec_calls(http_calls).await?;
 
         Ok(result)
     }
@@ -0,0 +1,59 @@
+use std::sync::Mutex;
+
+use futures::future::BoxFuture;
+use futures::FutureExt;
+
+use crate::error::SchemaRegistryError;
+use crate::types::{AttemptOutcome, FailoverAttempt, FailoverReport};
+
+/// Collects the outcome of every call sent to a URL.
+#[derive(Debug, Default)]
+pub(crate) struct ReportCollector {
+    attempts: Mutex<Vec<FailoverAttempt>>,
+}
+
+impl ReportCollector {
+    /// Wrap the calls so their outcome is recorded against their URL.
+    ///
+    /// Every URL is recorded as skipped until its call completes.
+    pub(crate) fn track<'a, T: Send + 'a>(
+        &'a self,
+        calls: Vec<(&'a str, BoxFuture<'a, Result<T, SchemaRegistryError>>)>,
+    ) -> Vec<(&'a str, BoxFuture<'a, Result<T, SchemaRegistryError>>)> {
+        let mut attempts = self.attempts.lock().expect("report lock poisoned");
+
+        calls
+            .into_iter()
+            .map(|(url, call)| {
+                let index = attempts.len();
+                attempts.push(FailoverAttempt {
+                    url: url.to_owned(),
+                    outcome: AttemptOutcome::Skipped,
+                });
+
+                let call = async move {
+                    let result = call.await;
+                    let outcome = match &result {
+                        Ok(_) => AttemptOutcome::Succeeded,
+                        Err(e) => AttemptOutcome::Failed {
+                            message: e.to_string(),
+                        },
+                    };
+
+                    self.attempts.lock().expect("report lock poisoned")[index].outcome = outcome;
+                    result
+                }
+                .boxed();
+
+                (url, call)
+            })
+            .collect()
+    }
+
+    /// Take the attempts recorded so far.
+    pub(crate) fn take(&self) -> FailoverReport {
+        let attempts = std::mem::take(&mut *self.attempts.lock().expect("report lock poisoned"));
+
+        FailoverReport { attempts }
+    }
+}
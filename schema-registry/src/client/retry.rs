@@ -0,0 +1,158 @@
+//! Retrying calls that fail because the schema registry is unavailable.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::{RequestBuilder, Response};
+
+use crate::client::http_util::{retry_after, upstream_error};
+use crate::error::SchemaRegistryError;
+
+/// Callback invoked before waiting to retry a call
+pub type RetryCallback = Arc<dyn Fn(RetryInfo) + Send + Sync>;
+
+/// A retry about to happen
+#[derive(Debug)]
+pub struct RetryInfo {
+    /// The attempt that failed, starting at 1
+    pub attempt: u32,
+    /// The error the attempt failed with
+    pub error: SchemaRegistryError,
+    /// How long until the next attempt
+    pub delay: Duration,
+}
+
+/// How calls to a URL are retried when the schema registry is unavailable
+///
+/// Transport errors and 5xx responses are retried with an exponential backoff, starting at
+/// `base_delay` and doubling on every attempt up to `max_delay`, unless the response carries
+/// a `Retry-After` header whose delay, also capped at `max_delay`, is waited instead. Other
+/// errors, including 4xx responses, are returned right away.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    pub(crate) max_attempts: u32,
+    pub(crate) base_delay: Duration,
+    pub(crate) max_delay: Duration,
+    pub(crate) jitter: f64,
+    pub(crate) on_retry: Option<RetryCallback>,
+}
+
+impl RetryPolicy {
+    /// Try each call at most `max_attempts` times, the first attempt included
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+            jitter: 0.0,
+            on_retry: None,
+        }
+    }
+
+    /// Set the delay before the first retry, defaults to 100 milliseconds
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    /// Set the longest delay between two attempts, defaults to 10 seconds
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Randomly shorten each delay by up to this fraction of it, defaults to no jitter
+    ///
+    /// Spreads the retries of clients that failed at the same time. Clamped between 0 and 1,
+    /// `NaN` meaning no jitter.
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = if jitter.is_nan() {
+            0.0
+        } else {
+            jitter.clamp(0.0, 1.0)
+        };
+        self
+    }
+
+    /// Invoke a callback before waiting to retry a call
+    pub fn on_retry(mut self, callback: RetryCallback) -> Self {
+        self.on_retry = Some(callback);
+        self
+    }
+
+    /// The delay after the given failed attempt
+    fn delay(&self, attempt: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt - 1))
+            .min(self.max_delay);
+
+        if self.jitter == 0.0 {
+            return delay;
+        }
+
+        delay.mul_f64(1.0 - self.jitter * random_fraction())
+    }
+}
+
+/// A pseudo-random number between 0 and 1, good enough to spread retries.
+fn random_fraction() -> f64 {
+    let seed = RandomState::new().build_hasher().finish();
+
+    (seed >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Send a request, retrying it according to the policy.
+pub(crate) async fn send_retrying(
+    request: RequestBuilder,
+    retry: &RetryPolicy,
+) -> Result<Response, SchemaRegistryError> {
+    let mut attempt = 1;
+
+    loop {
+        // Requests with a streaming body cannot be cloned, so they are sent once
+        let Some(retried) = request.try_clone().filter(|_| attempt < retry.max_attempts) else {
+            return Ok(request.send().await?);
+        };
+
+        // 5xx responses are retried whatever their body maps to
+        let error = match retried.send().await {
+            Ok(response) if !response.status().is_server_error() => return Ok(response),
+            Ok(response) => {
+                let status = response.status().as_u16();
+                let url = response.url().to_string();
+                let retry_after = retry_after(response.headers());
+                let bytes = response.bytes().await?;
+
+                upstream_error(url, status, retry_after, &bytes)
+            }
+            Err(e) => {
+                let error = SchemaRegistryError::from(e);
+
+                if !error.is_unavailable() {
+                    return Err(error);
+                }
+
+                error
+            }
+        };
+
+        let delay = error
+            .retry_after()
+            .map(|delay| delay.min(retry.max_delay))
+            .unwrap_or_else(|| retry.delay(attempt));
+
+        if let Some(on_retry) = &retry.on_retry {
+            on_retry(RetryInfo {
+                attempt,
+                error,
+                delay,
+            });
+        }
+
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
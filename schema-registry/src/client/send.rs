@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use http::header;
+use reqwest::{RequestBuilder, Response, ResponseBuilderExt};
+use tracing::trace;
+
+use crate::client::config::AuthProvider;
+use crate::client::retry::{send_retrying, RetryPolicy};
+use crate::error::SchemaRegistryError;
+
+/// How every request of a client is sent
+#[derive(Default)]
+pub(crate) struct SendOptions {
+    pub(crate) retry: Option<RetryPolicy>,
+    /// Provider of the `Authorization` header, asked before every request
+    pub(crate) auth: Option<Arc<dyn AuthProvider>>,
+    /// Maximum length of the response bodies logged, `None` to not log them
+    pub(crate) log_bodies: Option<usize>,
+}
+
+/// Send a request according to the options of the client.
+pub(crate) trait SendWith {
+    fn send_with(
+        self,
+        options: &SendOptions,
+    ) -> BoxFuture<'_, Result<Response, SchemaRegistryError>>;
+}
+
+impl SendWith for RequestBuilder {
+    fn send_with(
+        self,
+        options: &SendOptions,
+    ) -> BoxFuture<'_, Result<Response, SchemaRegistryError>> {
+        async move {
+            let request = match &options.auth {
+                Some(auth) => self.header(header::AUTHORIZATION, auth.header().await?),
+                None => self,
+            };
+
+            let response = match &options.retry {
+                Some(retry) => send_retrying(request, retry).await?,
+                None => request.send().await?,
+            };
+
+            match options.log_bodies {
+                Some(limit) => log_body(response, limit).await,
+                None => Ok(response),
+            }
+        }
+        .boxed()
+    }
+}
+
+/// Log the body of a response, handing back an equivalent response.
+///
+/// The body is read in full, so streamed responses are buffered.
+async fn log_body(response: Response, limit: usize) -> Result<Response, SchemaRegistryError> {
+    let status = response.status();
+    let version = response.version();
+    let url = response.url().clone();
+    let headers = response.headers().clone();
+    let bytes = response.bytes().await?;
+
+    let body = String::from_utf8_lossy(&bytes);
+    let truncated = match body.char_indices().nth(limit) {
+        Some((end, _)) => &body[..end],
+        None => &body,
+    };
+
+    trace!(%url, status = status.as_u16(), body = truncated, "schema registry response");
+
+    let mut builder = http::Response::builder()
+        .status(status)
+        .version(version)
+        .url(url);
+
+    if let Some(builder_headers) = builder.headers_mut() {
+        *builder_headers = headers;
+    }
+
+    let response = builder
+        .body(bytes)
+        .map_err(|e| SchemaRegistryError::Other(e.into()))?;
+
+    Ok(Response::from(response))
+}
@@ -1,9 +1,12 @@
 use std::error::Error as StdError;
 use std::io;
+use std::time::Duration;
 
 use reqwest::header::{InvalidHeaderName, InvalidHeaderValue};
 use thiserror::Error as ThisError;
 
+use crate::types::FailoverReport;
+
 pub type BoxError = Box<dyn StdError + Send + Sync>;
 
 #[derive(Debug, ThisError)]
@@ -31,6 +34,17 @@ pub enum ConfigurationError {
         #[from]
         source: reqwest::Error,
     },
+
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    #[error("Error parsing certificate: {source}")]
+    Certificate { source: reqwest::Error },
+
+    #[error("URL '{url}' is not one of the configured schema registry URLs")]
+    UnknownUrl { url: String },
+
+    #[cfg(feature = "blocking")]
+    #[error("Error starting the blocking client runtime: {source}")]
+    Runtime { source: io::Error },
 }
 
 #[derive(Debug, ThisError)]
@@ -47,6 +61,12 @@ pub enum HttpCallError {
         url: String,
         status: u16,
         body: String,
+        /// The registry error code, when the body carries one
+        error_code: Option<i32>,
+        /// The registry error message, when the body carries one
+        message: Option<String>,
+        /// How long to wait before retrying, when the response carries a `Retry-After` header
+        retry_after: Option<Duration>,
     },
 
     #[error("Unexpected HTTP Call error: {source}")]
@@ -64,20 +84,273 @@ pub enum SchemaRegistryError {
     #[error(transparent)]
     HttpCall(#[from] HttpCallError),
 
+    #[error("Subject not found: {message}")]
+    SubjectNotFound { message: String },
+
+    #[error("Version not found: {message}")]
+    VersionNotFound { message: String },
+
+    #[error("Schema not found: {message}")]
+    SchemaNotFound { message: String },
+
+    #[error("Incompatible schema: {message}")]
+    IncompatibleSchema { message: String },
+
+    #[error("Invalid version: {message}")]
+    InvalidVersion { message: String },
+
+    #[error("Schema is still referenced: {message}")]
+    ReferenceExists { message: String },
+
+    #[error("Schema Registry error {error_code}: {message}")]
+    Registry { error_code: i32, message: String },
+
     #[error("Error parsing invalid schema type: {message}")]
     InvalidSchemaType { message: String },
 
+    #[error("Error validating schema: {message}")]
+    InvalidSchema { message: String },
+
     #[error("Error parsing compatibility level: {message}")]
     InvalidCompatibilityLevel { message: String },
 
+    #[error(
+        "Refusing to delete version {version} of subject '{subject}': it is the only version left"
+    )]
+    LastSubjectVersion { subject: String, version: u32 },
+
+    #[error("Reference cycle through version {version} of subject '{subject}'")]
+    ReferenceCycle { subject: String, version: u32 },
+
+    #[error("Permanent deletes are disabled, refusing to permanently delete '{subject}'")]
+    PermanentDeleteDisabled { subject: String },
+
+    #[error("Error decoding wire format: {message}")]
+    InvalidWireFormat { message: String },
+
+    #[error("{source}")]
+    Failover {
+        report: FailoverReport,
+        source: Box<SchemaRegistryError>,
+    },
+
+    #[error("Timed out: {message}")]
+    Timeout { message: String },
+
     #[error("Error: {0}")]
     Other(BoxError),
 }
 
+impl From<reqwest::Error> for SchemaRegistryError {
+    fn from(source: reqwest::Error) -> Self {
+        SchemaRegistryError::HttpCall(HttpCallError::from(source))
+    }
+}
+
 impl SchemaRegistryError {
+    /// Map a Confluent `error_code` to its semantic error
+    ///
+    /// Codes without a dedicated variant are kept as [`SchemaRegistryError::Registry`].
+    pub fn from_error_code(code: i32, message: String) -> Self {
+        match code {
+            40401 => SchemaRegistryError::SubjectNotFound { message },
+            40402 => SchemaRegistryError::VersionNotFound { message },
+            40403 => SchemaRegistryError::SchemaNotFound { message },
+            40901 => SchemaRegistryError::IncompatibleSchema { message },
+            42201 => SchemaRegistryError::InvalidSchema { message },
+            42202 => SchemaRegistryError::InvalidVersion { message },
+            42203 => SchemaRegistryError::InvalidCompatibilityLevel { message },
+            42206 => SchemaRegistryError::ReferenceExists { message },
+            error_code => SchemaRegistryError::Registry {
+                error_code,
+                message,
+            },
+        }
+    }
+
+    /// The URLs the failed calls were sent to, when run with
+    /// [`SchemaRegistryClient::with_failover_report`](crate::client::SchemaRegistryClient::with_failover_report)
+    pub fn failover_report(&self) -> Option<&FailoverReport> {
+        match self {
+            SchemaRegistryError::Failover { report, .. } => Some(report),
+            _ => None,
+        }
+    }
+
+    /// How long the schema registry asked to wait before retrying, from the `Retry-After`
+    /// header of its response
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            SchemaRegistryError::HttpCall(HttpCallError::UpstreamError { retry_after, .. }) => {
+                *retry_after
+            }
+            SchemaRegistryError::Failover { source, .. } => source.retry_after(),
+            _ => None,
+        }
+    }
+
+    /// Whether the error shows the schema registry could not serve the request
+    pub(crate) fn is_unavailable(&self) -> bool {
+        match self {
+            SchemaRegistryError::HttpCall(HttpCallError::Unexpected { .. }) => true,
+            SchemaRegistryError::HttpCall(HttpCallError::UpstreamError { status, .. }) => {
+                *status >= 500
+            }
+            SchemaRegistryError::Registry { error_code, .. } => {
+                (500..600).contains(error_code) || error_code / 100 >= 500
+            }
+            SchemaRegistryError::Failover { source, .. } => source.is_unavailable(),
+            _ => false,
+        }
+    }
+
+    /// Whether the error reports a missing resource, whatever its kind
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            SchemaRegistryError::SubjectNotFound { .. }
+            | SchemaRegistryError::VersionNotFound { .. }
+            | SchemaRegistryError::SchemaNotFound { .. } => true,
+            SchemaRegistryError::Registry { error_code, .. } => error_code / 100 == 404,
+            SchemaRegistryError::HttpCall(HttpCallError::UpstreamError { status, .. }) => {
+                *status == 404
+            }
+            SchemaRegistryError::Failover { source, .. } => source.is_not_found(),
+            _ => false,
+        }
+    }
+
+    /// Whether the error reports a missing subject (40401)
+    pub fn is_subject_not_found(&self) -> bool {
+        match self {
+            SchemaRegistryError::SubjectNotFound { .. } => true,
+            SchemaRegistryError::Failover { source, .. } => source.is_subject_not_found(),
+            _ => self.upstream_error_code() == Some(40401),
+        }
+    }
+
+    /// Whether the error reports a missing subject version (40402)
+    pub fn is_version_not_found(&self) -> bool {
+        match self {
+            SchemaRegistryError::VersionNotFound { .. } => true,
+            SchemaRegistryError::Failover { source, .. } => source.is_version_not_found(),
+            _ => self.upstream_error_code() == Some(40402),
+        }
+    }
+
+    /// Whether the error reports a missing schema (40403)
+    pub fn is_schema_not_found(&self) -> bool {
+        match self {
+            SchemaRegistryError::SchemaNotFound { .. } => true,
+            SchemaRegistryError::Failover { source, .. } => source.is_schema_not_found(),
+            _ => self.upstream_error_code() == Some(40403),
+        }
+    }
+
+    /// The error code of a registry error not mapped to a semantic variant
+    fn upstream_error_code(&self) -> Option<i32> {
+        match self {
+            SchemaRegistryError::Registry { error_code, .. } => Some(*error_code),
+            SchemaRegistryError::HttpCall(HttpCallError::UpstreamError { error_code, .. }) => {
+                *error_code
+            }
+            SchemaRegistryError::Failover { source, .. } => source.upstream_error_code(),
+            _ => None,
+        }
+    }
+
     pub fn invalid_schema_type<T: ToString>(s: T) -> Self {
         SchemaRegistryError::InvalidSchemaType {
             message: s.to_string(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::error::SchemaRegistryError;
+    use crate::types::FailoverReport;
+
+    #[test]
+    fn not_found_codes_map_to_their_resource() {
+        let subject = SchemaRegistryError::from_error_code(40401, "Subject not found".into());
+        let version = SchemaRegistryError::from_error_code(40402, "Version not found".into());
+        let schema = SchemaRegistryError::from_error_code(40403, "Schema not found".into());
+
+        assert!(matches!(
+            subject,
+            SchemaRegistryError::SubjectNotFound { .. }
+        ));
+        assert!(matches!(
+            version,
+            SchemaRegistryError::VersionNotFound { .. }
+        ));
+        assert!(matches!(schema, SchemaRegistryError::SchemaNotFound { .. }));
+        assert!(subject.is_not_found() && version.is_not_found() && schema.is_not_found());
+    }
+
+    #[test]
+    fn conflict_and_validation_codes_map_to_semantic_variants() {
+        assert!(matches!(
+            SchemaRegistryError::from_error_code(40901, "Incompatible schema".into()),
+            SchemaRegistryError::IncompatibleSchema { .. }
+        ));
+        assert!(matches!(
+            SchemaRegistryError::from_error_code(42201, "Invalid schema".into()),
+            SchemaRegistryError::InvalidSchema { .. }
+        ));
+        assert!(matches!(
+            SchemaRegistryError::from_error_code(42202, "Invalid version".into()),
+            SchemaRegistryError::InvalidVersion { .. }
+        ));
+        assert!(matches!(
+            SchemaRegistryError::from_error_code(42203, "Invalid compatibility level".into()),
+            SchemaRegistryError::InvalidCompatibilityLevel { .. }
+        ));
+        assert!(matches!(
+            SchemaRegistryError::from_error_code(42206, "References exist".into()),
+            SchemaRegistryError::ReferenceExists { .. }
+        ));
+    }
+
+    #[test]
+    fn unknown_codes_keep_code_and_message() {
+        let error = SchemaRegistryError::from_error_code(40408, "Subject not configured".into());
+
+        assert!(error.is_not_found());
+        assert!(matches!(
+            error,
+            SchemaRegistryError::Registry {
+                error_code: 40408,
+                ref message,
+            } if message == "Subject not configured"
+        ));
+    }
+
+    #[test]
+    fn plain_server_error_codes_are_unavailable() {
+        for code in [500, 502, 503, 50001] {
+            let error = SchemaRegistryError::from_error_code(code, "Unavailable".into());
+
+            assert!(error.is_unavailable());
+        }
+
+        assert!(
+            !SchemaRegistryError::from_error_code(40101, "Unauthorized".into()).is_unavailable()
+        );
+    }
+
+    #[test]
+    fn failover_wrapped_not_found_keeps_its_resource() {
+        let error = SchemaRegistryError::Failover {
+            report: FailoverReport::default(),
+            source: Box::new(SchemaRegistryError::from_error_code(
+                40401,
+                "Subject not found".into(),
+            )),
+        };
+
+        assert!(error.is_not_found());
+        assert!(error.is_subject_not_found());
+        assert!(!error.is_version_not_found() && !error.is_schema_not_found());
+    }
+}
@@ -1,6 +1,12 @@
 //! A Rust client and API specification for Confluent Schema Registry.
 
 pub mod api;
+pub mod backup;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod client;
 pub mod error;
+#[cfg(feature = "test-util")]
+pub mod test_fixtures;
 pub mod types;
+pub mod wire;
@@ -0,0 +1,129 @@
+//! Canned schema registry values for tests.
+//!
+//! These mirror what a registry returns so they can be handed to a `MockSchemaRegistryAPI`
+//! without building every value by hand.
+
+use std::collections::HashMap;
+
+use crate::types::{
+    ExporterConfig, ExporterState, ExporterStatus, Reference, Schema, SchemaType, Subject,
+    SubjectVersion,
+};
+
+/// An Avro record schema with no references.
+pub const AVRO_AUTHOR: &str = r#"{"type":"record","name":"Author","namespace":"com.example","fields":[{"name":"id","type":"int"},{"name":"name","type":"string"}]}"#;
+
+/// A Protobuf schema with no references.
+pub const PROTOBUF_AUTHOR: &str = r#"syntax = "proto3";
+
+package com.example;
+
+message Author {
+  int64 id = 1;
+  string name = 2;
+}
+"#;
+
+/// A JSON schema with no references.
+pub const JSON_AUTHOR: &str = r#"{"type":"object","properties":{"id":{"type":"integer"},"name":{"type":"string"}},"required":["id","name"]}"#;
+
+/// The `author-value` subject at version 1 holding [`AVRO_AUTHOR`].
+pub fn sample_avro_subject() -> Subject {
+    Subject {
+        id: 1,
+        subject: "author-value".to_owned(),
+        version: 1,
+        schema_type: SchemaType::Avro,
+        schema: AVRO_AUTHOR.into(),
+        references: None,
+        metadata: None,
+        rule_set: None,
+    }
+}
+
+/// The `book-value` subject at version 1, referencing `author-value`.
+pub fn sample_avro_subject_with_reference() -> Subject {
+    Subject {
+        id: 2,
+        subject: "book-value".to_owned(),
+        version: 1,
+        schema_type: SchemaType::Avro,
+        schema: r#"{"type":"record","name":"Book","namespace":"com.example","fields":[{"name":"id","type":"int"},{"name":"author","type":"com.example.Author"}]}"#.into(),
+        references: Some(vec![Reference::new("com.example.Author", "author-value")]),
+        metadata: None,
+        rule_set: None,
+    }
+}
+
+/// An Avro schema as returned when fetching by id.
+pub fn sample_avro_schema() -> Schema {
+    Schema {
+        schema_type: SchemaType::Avro,
+        schema: AVRO_AUTHOR.into(),
+        references: None,
+        metadata: None,
+        rule_set: None,
+    }
+}
+
+/// A Protobuf schema as returned when fetching by id.
+pub fn sample_protobuf_schema() -> Schema {
+    Schema {
+        schema_type: SchemaType::Protobuf,
+        schema: PROTOBUF_AUTHOR.into(),
+        references: None,
+        metadata: None,
+        rule_set: None,
+    }
+}
+
+/// A JSON schema as returned when fetching by id.
+pub fn sample_json_schema() -> Schema {
+    Schema {
+        schema_type: SchemaType::Json,
+        schema: JSON_AUTHOR.into(),
+        references: None,
+        metadata: None,
+        rule_set: None,
+    }
+}
+
+/// The subject-version pairs a schema id is registered under.
+pub fn sample_subject_versions() -> Vec<SubjectVersion> {
+    vec![
+        SubjectVersion {
+            subject: "author-value".to_owned(),
+            version: 1,
+        },
+        SubjectVersion {
+            subject: "writer-value".to_owned(),
+            version: 3,
+        },
+    ]
+}
+
+/// A running exporter.
+pub fn sample_exporter_status() -> ExporterStatus {
+    ExporterStatus {
+        name: "exporter".to_owned(),
+        state: ExporterState::Running,
+        offset: 42,
+        ts: 1_700_000_000_000,
+        trace: None,
+    }
+}
+
+/// An exporter copying every subject to another registry.
+pub fn sample_exporter_config() -> ExporterConfig {
+    ExporterConfig {
+        name: Some("exporter".to_owned()),
+        context_type: Some("AUTO".to_owned()),
+        context: None,
+        subjects: Some(vec!["*".to_owned()]),
+        subject_rename_format: None,
+        config: HashMap::from([(
+            "schema.registry.url".to_owned(),
+            "http://destination:8081".to_owned(),
+        )]),
+    }
+}
@@ -12,15 +12,85 @@ pub(crate) struct CompatibilityCheck {
     pub is_compatible: bool,
 }
 
+/// The outcome of a verbose compatibility check
+///
+/// Serializes as `{"compatible": bool, "messages": [..]}` and also accepts the registry's
+/// `is_compatible` field name when deserializing.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CompatibilityResult {
+    #[serde(alias = "is_compatible")]
+    pub compatible: bool,
+    #[serde(default)]
+    pub messages: Vec<String>,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct ExporterStatus {
     pub name: String,
-    pub state: String,
+    pub state: ExporterState,
     pub offset: i64,
     pub ts: i64,
     pub trace: Option<String>,
 }
 
+/// The state of an exporter
+///
+/// States unknown to this crate are kept as [`ExporterState::Unknown`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum ExporterState {
+    Starting,
+    Running,
+    Paused,
+    Stopped,
+    Unknown(String),
+}
+
+impl fmt::Display for ExporterState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExporterState::Starting => write!(f, "STARTING"),
+            ExporterState::Running => write!(f, "RUNNING"),
+            ExporterState::Paused => write!(f, "PAUSED"),
+            ExporterState::Stopped => write!(f, "STOPPED"),
+            ExporterState::Unknown(other) => write!(f, "{}", other),
+        }
+    }
+}
+
+impl FromStr for ExporterState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        match str {
+            s if s.eq_ignore_ascii_case("STARTING") => Ok(ExporterState::Starting),
+            s if s.eq_ignore_ascii_case("RUNNING") => Ok(ExporterState::Running),
+            s if s.eq_ignore_ascii_case("PAUSED") => Ok(ExporterState::Paused),
+            s if s.eq_ignore_ascii_case("STOPPED") => Ok(ExporterState::Stopped),
+            s => Ok(ExporterState::Unknown(s.to_owned())),
+        }
+    }
+}
+
+impl Serialize for ExporterState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ExporterState {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let state = Cow::<'de, str>::deserialize(deserializer)?;
+
+        state.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ExporterConfig {
@@ -47,6 +117,7 @@ pub struct ClusterConfig {
     pub(crate) normalize: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename(serialize = "compatibility", deserialize = "compatibilityLevel"))]
+    #[serde(alias = "compatibility")]
     pub(crate) compatibility_level: Option<CompatibilityLevel>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) compatibility_group: Option<String>,
@@ -115,6 +186,7 @@ pub struct SubjectConfig {
     pub(crate) normalize: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename(serialize = "compatibility", deserialize = "compatibilityLevel"))]
+    #[serde(alias = "compatibility")]
     pub(crate) compatibility_level: Option<CompatibilityLevel>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) compatibility_group: Option<String>,
@@ -172,13 +244,90 @@ impl SubjectConfig {
         self.override_rule_set = Some(override_rule_set);
         self
     }
+
+    /// Replace the fields set in `patch`, keeping the others
+    pub(crate) fn overlay(self, patch: SubjectConfig) -> Self {
+        Self {
+            alias: patch.alias.or(self.alias),
+            normalize: patch.normalize.or(self.normalize),
+            compatibility_level: patch.compatibility_level.or(self.compatibility_level),
+            compatibility_group: patch.compatibility_group.or(self.compatibility_group),
+            default_metadata: patch.default_metadata.or(self.default_metadata),
+            override_metadata: patch.override_metadata.or(self.override_metadata),
+            default_rule_set: patch.default_rule_set.or(self.default_rule_set),
+            override_rule_set: patch.override_rule_set.or(self.override_rule_set),
+        }
+    }
+}
+
+/// The policy governing schema evolution of a subject under data contracts
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ContractPolicy {
+    pub compatibility_level: Option<CompatibilityLevel>,
+    /// Name of the metadata property that partitions versions into compatibility groups
+    pub compatibility_group: Option<String>,
+    /// Default metadata overlaid with the override metadata
+    pub metadata: HashMap<String, String>,
+}
+
+impl From<SubjectConfig> for ContractPolicy {
+    fn from(config: SubjectConfig) -> Self {
+        let metadata = config
+            .default_metadata
+            .into_iter()
+            .chain(config.override_metadata)
+            .flatten()
+            .collect();
+
+        Self {
+            compatibility_level: config.compatibility_level,
+            compatibility_group: config.compatibility_group,
+            metadata,
+        }
+    }
+}
+
+impl From<ClusterConfig> for ContractPolicy {
+    fn from(config: ClusterConfig) -> Self {
+        let metadata = config
+            .default_metadata
+            .into_iter()
+            .chain(config.override_metadata)
+            .flatten()
+            .collect();
+
+        Self {
+            compatibility_level: config.compatibility_level,
+            compatibility_group: config.compatibility_group,
+            metadata,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
 pub(crate) struct Id {
+    #[serde(deserialize_with = "deserialize_id")]
     pub id: u32,
 }
 
+/// Deserialize a schema id sent either as a number or, by some proxies, as a string
+fn deserialize_id<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberOrString<'a> {
+        Number(u32),
+        String(Cow<'a, str>),
+    }
+
+    match NumberOrString::deserialize(deserializer)? {
+        NumberOrString::Number(id) => Ok(id),
+        NumberOrString::String(id) => id.parse().map_err(serde::de::Error::custom),
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
 pub(crate) struct ResourceMode {
     pub mode: Mode,
@@ -195,7 +344,70 @@ pub enum Mode {
     Import,
 }
 
-#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+/// A serializable copy of a registry, merged into [`RegistrySnapshot`](crate::backup::RegistrySnapshot)
+#[deprecated(note = "use `backup::RegistrySnapshot` instead")]
+pub type RegistryBundle = crate::backup::RegistrySnapshot;
+
+/// The differences between a live registry and a snapshot, merged into
+/// [`SnapshotDiff`](crate::backup::SnapshotDiff)
+#[deprecated(note = "use `backup::SnapshotDiff` instead")]
+pub type BundleDiff = crate::backup::SnapshotDiff;
+
+/// The URLs calls were sent to and how each attempt went, in order
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct FailoverReport {
+    pub attempts: Vec<FailoverAttempt>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FailoverAttempt {
+    pub url: String,
+    pub outcome: AttemptOutcome,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum AttemptOutcome {
+    Succeeded,
+    Failed {
+        message: String,
+    },
+    /// The URL was not called, or its call was abandoned once another URL succeeded
+    Skipped,
+}
+
+/// The schema registry implementation serving the API
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum RegistryFlavor {
+    Confluent,
+    Apicurio,
+    Redpanda,
+    Unknown,
+}
+
+/// The identity of the cluster a schema registry belongs to
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ClusterId {
+    pub scope: ClusterScope,
+    pub id: String,
+}
+
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ClusterScope {
+    pub path: Vec<String>,
+    /// Cluster ids by cluster kind, such as `kafka-cluster` or `schema-registry-cluster`
+    pub clusters: HashMap<String, String>,
+}
+
+/// The build a schema registry is running
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerVersion {
+    pub version: String,
+    pub commit_id: String,
+}
+
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum CompatibilityLevel {
     #[default]
@@ -222,13 +434,61 @@ impl fmt::Display for CompatibilityLevel {
     }
 }
 
-#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+impl FromStr for CompatibilityLevel {
+    type Err = SchemaRegistryError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        match str {
+            s if s.eq_ignore_ascii_case("BACKWARD") => Ok(CompatibilityLevel::Backward),
+            s if s.eq_ignore_ascii_case("BACKWARD_TRANSITIVE") => {
+                Ok(CompatibilityLevel::BackwardTransitive)
+            }
+            s if s.eq_ignore_ascii_case("FORWARD") => Ok(CompatibilityLevel::Forward),
+            s if s.eq_ignore_ascii_case("FORWARD_TRANSITIVE") => {
+                Ok(CompatibilityLevel::ForwardTransitive)
+            }
+            s if s.eq_ignore_ascii_case("FULL") => Ok(CompatibilityLevel::Full),
+            s if s.eq_ignore_ascii_case("FULL_TRANSITIVE") => {
+                Ok(CompatibilityLevel::FullTransitive)
+            }
+            s if s.eq_ignore_ascii_case("NONE") => Ok(CompatibilityLevel::None),
+            s => Err(SchemaRegistryError::InvalidCompatibilityLevel {
+                message: format!("unknown compatibility level '{}'", s),
+            }),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CompatibilityLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let level = Cow::<'de, str>::deserialize(deserializer)?;
+
+        level.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum Version {
     #[default]
     Latest,
     Number(u32),
 }
 
+impl Version {
+    /// Create a version from its number, rejecting `0` as versions start at `1`
+    pub fn number(version: u32) -> Result<Self, SchemaRegistryError> {
+        match version {
+            0 => Err(SchemaRegistryError::InvalidVersion {
+                message: "versions start at 1".to_owned(),
+            }),
+            version => Ok(Version::Number(version)),
+        }
+    }
+}
+
 impl fmt::Display for Version {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -238,13 +498,139 @@ impl fmt::Display for Version {
     }
 }
 
-#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+impl FromStr for Version {
+    type Err = SchemaRegistryError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        if str.eq_ignore_ascii_case("latest") {
+            return Ok(Version::Latest);
+        }
+
+        let version = str
+            .parse::<u32>()
+            .map_err(|_| SchemaRegistryError::InvalidVersion {
+                message: format!("unknown version '{}'", str),
+            })?;
+
+        Version::number(version)
+    }
+}
+
+impl Serialize for Version {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Version {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct VersionVisitor;
+
+        impl serde::de::Visitor<'_> for VersionVisitor {
+            type Value = Version;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "\"latest\" or a version number starting at 1")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Version, E> {
+                v.parse().map_err(E::custom)
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Version, E> {
+                let version = u32::try_from(v).map_err(E::custom)?;
+
+                Version::number(version).map_err(E::custom)
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Version, E> {
+                let version = u64::try_from(v).map_err(E::custom)?;
+
+                self.visit_u64(version)
+            }
+        }
+
+        deserializer.deserialize_any(VersionVisitor)
+    }
+}
+
+/// A JSON Schema specification draft, see [`UnregisteredSchema::json_schema_draft`]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum JsonSchemaDraft {
+    Draft4,
+    Draft6,
+    Draft7,
+    Draft2019_09,
+    Draft2020_12,
+}
+
+impl JsonSchemaDraft {
+    /// The `$schema` URI identifying the draft
+    pub fn uri(&self) -> &'static str {
+        match self {
+            JsonSchemaDraft::Draft4 => "http://json-schema.org/draft-04/schema#",
+            JsonSchemaDraft::Draft6 => "http://json-schema.org/draft-06/schema#",
+            JsonSchemaDraft::Draft7 => "http://json-schema.org/draft-07/schema#",
+            JsonSchemaDraft::Draft2019_09 => "https://json-schema.org/draft/2019-09/schema",
+            JsonSchemaDraft::Draft2020_12 => "https://json-schema.org/draft/2020-12/schema",
+        }
+    }
+
+    /// The draft a `$schema` URI identifies, whatever its scheme and trailing empty fragment
+    pub fn from_uri(uri: &str) -> Option<Self> {
+        let normalize = |uri: &str| {
+            let uri = uri.trim().trim_end_matches('#');
+            let uri = uri
+                .strip_prefix("https://")
+                .or_else(|| uri.strip_prefix("http://"))
+                .unwrap_or(uri);
+
+            uri.to_owned()
+        };
+
+        let uri = normalize(uri);
+
+        [
+            JsonSchemaDraft::Draft4,
+            JsonSchemaDraft::Draft6,
+            JsonSchemaDraft::Draft7,
+            JsonSchemaDraft::Draft2019_09,
+            JsonSchemaDraft::Draft2020_12,
+        ]
+        .into_iter()
+        .find(|draft| normalize(draft.uri()) == uri)
+    }
+}
+
+impl fmt::Display for JsonSchemaDraft {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonSchemaDraft::Draft4 => write!(f, "draft-04"),
+            JsonSchemaDraft::Draft6 => write!(f, "draft-06"),
+            JsonSchemaDraft::Draft7 => write!(f, "draft-07"),
+            JsonSchemaDraft::Draft2019_09 => write!(f, "2019-09"),
+            JsonSchemaDraft::Draft2020_12 => write!(f, "2020-12"),
+        }
+    }
+}
+
+/// The type of a schema
+///
+/// Types other than the built-in ones, such as those of custom schema providers, are kept
+/// as [`SchemaType::Other`].
+#[derive(Default, Debug, Clone, Eq, PartialEq, Hash)]
 pub enum SchemaType {
     #[default]
     Avro,
     Protobuf,
     Json,
+    Other(String),
 }
 
 impl fmt::Display for SchemaType {
@@ -253,6 +639,7 @@ impl fmt::Display for SchemaType {
             SchemaType::Avro => write!(f, "AVRO"),
             SchemaType::Protobuf => write!(f, "PROTOBUF"),
             SchemaType::Json => write!(f, "JSON"),
+            SchemaType::Other(other) => write!(f, "{}", other),
         }
     }
 }
@@ -265,11 +652,32 @@ impl FromStr for SchemaType {
             s if s.eq_ignore_ascii_case("AVRO") => Ok(SchemaType::Avro),
             s if s.eq_ignore_ascii_case("PROTOBUF") => Ok(SchemaType::Protobuf),
             s if s.eq_ignore_ascii_case("JSON") => Ok(SchemaType::Json),
-            _ => Err(SchemaRegistryError::invalid_schema_type(str)),
+            s if s.trim().is_empty() => Err(SchemaRegistryError::invalid_schema_type(str)),
+            s => Ok(SchemaType::Other(s.to_owned())),
         }
     }
 }
 
+impl Serialize for SchemaType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SchemaType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let schema_type = Cow::<'de, str>::deserialize(deserializer)?;
+
+        schema_type.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LookupSubject {
@@ -282,7 +690,7 @@ pub struct LookupSubject {
 #[serde(transparent)]
 pub struct StringSchema(pub Cow<'static, str>);
 
-#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct SubjectVersion {
     pub subject: String,
     pub version: u32,
@@ -295,9 +703,23 @@ pub struct Schema {
     pub schema_type: SchemaType,
     pub schema: Cow<'static, str>,
     pub references: Option<Vec<Reference>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule_set: Option<RuleSet>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+impl Schema {
+    /// The schema text indented for display
+    ///
+    /// Avro and JSON schemas are re-serialized with indentation, other schemas and text
+    /// that fails to parse are returned as is.
+    pub fn pretty(&self) -> String {
+        pretty_schema(&self.schema_type, &self.schema)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Subject {
     pub id: u32,
@@ -307,6 +729,133 @@ pub struct Subject {
     pub schema_type: SchemaType,
     pub schema: Cow<'static, str>,
     pub references: Option<Vec<Reference>>,
+    #[serde(default)]
+    pub metadata: Option<Metadata>,
+    #[serde(default)]
+    pub rule_set: Option<RuleSet>,
+}
+
+impl From<Subject> for Schema {
+    fn from(subject: Subject) -> Self {
+        Self {
+            schema_type: subject.schema_type,
+            schema: subject.schema,
+            references: subject.references,
+            metadata: subject.metadata,
+            rule_set: subject.rule_set,
+        }
+    }
+}
+
+/// A schema along with every schema it references, transitively
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ResolvedSchema {
+    pub root: Schema,
+    /// The referenced schemas in dependency order, dependencies first
+    pub dependencies: Vec<(Reference, Schema)>,
+}
+
+impl Subject {
+    /// The schema text indented for display
+    ///
+    /// See [`Schema::pretty`].
+    pub fn pretty(&self) -> String {
+        pretty_schema(&self.schema_type, &self.schema)
+    }
+}
+
+fn pretty_schema(schema_type: &SchemaType, schema: &str) -> String {
+    match schema_type {
+        SchemaType::Avro | SchemaType::Json => serde_json::from_str::<serde_json::Value>(schema)
+            .and_then(|value| serde_json::to_string_pretty(&value))
+            .unwrap_or_else(|_| schema.to_owned()),
+        SchemaType::Protobuf | SchemaType::Other(_) => schema.to_owned(),
+    }
+}
+
+/// Check whether two schema strings describe the same schema, without calling the registry
+///
+/// Avro schemas are compared by their parsing canonical form when the `avro` feature is
+/// enabled, and JSON schemas as parsed JSON values when the `json-schema` feature is enabled.
+/// Anything else is compared as text with whitespace normalized.
+pub fn schema_equivalent(
+    a: &str,
+    b: &str,
+    schema_type: SchemaType,
+) -> Result<bool, SchemaRegistryError> {
+    match schema_type {
+        #[cfg(feature = "avro")]
+        SchemaType::Avro => {
+            let parse = |schema| {
+                apache_avro::Schema::parse_str(schema)
+                    .map(|schema| schema.canonical_form())
+                    .map_err(|e| SchemaRegistryError::InvalidSchema {
+                        message: e.to_string(),
+                    })
+            };
+
+            Ok(parse(a)? == parse(b)?)
+        }
+        #[cfg(feature = "json-schema")]
+        SchemaType::Json => {
+            let parse = |schema| {
+                serde_json::from_str::<serde_json::Value>(schema).map_err(|e| {
+                    SchemaRegistryError::InvalidSchema {
+                        message: e.to_string(),
+                    }
+                })
+            };
+
+            Ok(parse(a)? == parse(b)?)
+        }
+        _ => Ok(normalize_whitespace(a) == normalize_whitespace(b)),
+    }
+}
+
+fn normalize_whitespace(schema: &str) -> String {
+    schema.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The metadata of a schema under data contracts
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Metadata {
+    /// Tags of the schema, keyed by the path of the field they apply to
+    pub tags: HashMap<String, Vec<String>>,
+    pub properties: HashMap<String, String>,
+    /// Names of the properties holding sensitive values
+    pub sensitive: Vec<String>,
+}
+
+/// The rules of a schema under data contracts
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct RuleSet {
+    /// Rules applied to the data of a single schema version
+    pub domain_rules: Vec<Rule>,
+    /// Rules transforming data between schema versions
+    pub migration_rules: Vec<Rule>,
+}
+
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Rule {
+    pub name: String,
+    /// Either `TRANSFORM` or `CONDITION`
+    pub kind: String,
+    /// When the rule applies, such as `WRITE`, `READ` or `UPGRADE`
+    pub mode: String,
+    /// The rule executor, such as `CEL` or `ENCRYPT`
+    #[serde(rename = "type")]
+    pub rule_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expr: Option<String>,
+    /// The action taken when the rule succeeds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_success: Option<String>,
+    /// The action taken when the rule fails
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_failure: Option<String>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -332,18 +881,82 @@ impl Reference {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UnregisteredSchema {
     pub(crate) schema: String,
+    #[serde(default)]
     pub(crate) schema_type: SchemaType,
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) references: Option<Vec<Reference>>,
+    #[serde(default)]
+    pub(crate) id: Option<u32>,
+    #[serde(default)]
+    pub(crate) version: Option<u32>,
+    #[serde(skip)]
+    pub(crate) omit_default_schema_type: bool,
+    #[serde(skip)]
+    pub(crate) json_schema_draft: Option<JsonSchemaDraft>,
+}
+
+impl Serialize for UnregisteredSchema {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let omit_schema_type =
+            self.omit_default_schema_type && self.schema_type == SchemaType::default();
+
+        let mut state = serializer.serialize_struct("UnregisteredSchema", 5)?;
+        state.serialize_field("schema", &self.schema)?;
+
+        if omit_schema_type {
+            state.skip_field("schemaType")?;
+        } else {
+            state.serialize_field("schemaType", &self.schema_type)?;
+        }
+
+        if let Some(references) = &self.references {
+            state.serialize_field("references", references)?;
+        } else {
+            state.skip_field("references")?;
+        }
+
+        match self.id {
+            Some(id) => state.serialize_field("id", &id)?,
+            None => state.skip_field("id")?,
+        }
+
+        match self.version {
+            Some(version) => state.serialize_field("version", &version)?,
+            None => state.skip_field("version")?,
+        }
+
+        state.end()
+    }
 }
 
+/// The response to a registration, of which recent registries send more than the id
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct RegisteredSchema {
+    #[serde(deserialize_with = "deserialize_id")]
     pub id: u32,
+    #[serde(default)]
+    pub version: Option<u32>,
+    #[serde(default)]
+    pub subject: Option<String>,
+    #[serde(default)]
+    pub schema: Option<String>,
+}
+
+/// The outcome of registering a schema under a subject
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RegistrationOutcome {
+    pub id: u32,
+    pub version: u32,
+    /// Whether the registration created a new version, as opposed to matching an existing one
+    pub created: bool,
 }
 
 impl UnregisteredSchema {
@@ -355,6 +968,10 @@ impl UnregisteredSchema {
             schema: schema.into(),
             schema_type: SchemaType::Avro,
             references: None,
+            id: None,
+            version: None,
+            omit_default_schema_type: false,
+            json_schema_draft: None,
         }
     }
 
@@ -363,6 +980,38 @@ impl UnregisteredSchema {
         self
     }
 
+    /// Register the schema under the provided id, which requires the registry or the subject
+    /// to be in [`Mode::Import`]
+    pub fn with_id(mut self, id: u32) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Register the schema as the provided version of the subject, which requires the
+    /// registry or the subject to be in [`Mode::Import`]
+    pub fn with_version(mut self, version: u32) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Leave `schemaType` out of the request body when it is Avro, the registry's default
+    ///
+    /// Some strict proxies reject an explicit `"AVRO"` type on certain endpoints.
+    pub fn omit_default_schema_type(mut self, omit: bool) -> Self {
+        self.omit_default_schema_type = omit;
+        self
+    }
+
+    /// The JSON Schema draft the schema is written against
+    ///
+    /// The registry picks the draft from the `$schema` keyword of the schema, so the hint is
+    /// not sent. It is checked against `$schema` by [`UnregisteredSchema::validate`] when the
+    /// `json-schema` feature is enabled.
+    pub fn json_schema_draft(mut self, draft: JsonSchemaDraft) -> Self {
+        self.json_schema_draft = Some(draft);
+        self
+    }
+
     pub fn reference(mut self, reference: Reference) -> Self {
         if let Some(references) = self.references.as_mut() {
             references.push(reference);
@@ -385,4 +1034,753 @@ impl UnregisteredSchema {
 
         self
     }
+
+    /// Validate the schema locally, before any network call
+    ///
+    /// Avro schemas are parsed when the `avro` or `validate-avro` feature is enabled and JSON
+    /// schemas are checked to be well-formed, to match their
+    /// [draft](UnregisteredSchema::json_schema_draft) and with
+    /// [`UnregisteredSchema::validate_references`] when the `json-schema` feature is.
+    /// Other schemas are only checked not to be empty.
+    pub fn validate(&self) -> Result<(), SchemaRegistryError> {
+        if self.schema.trim().is_empty() {
+            return Err(SchemaRegistryError::InvalidSchema {
+                message: "schema is empty".to_owned(),
+            });
+        }
+
+        match self.schema_type {
+            #[cfg(feature = "avro")]
+            SchemaType::Avro => self.validate_avro(),
+            #[cfg(feature = "json-schema")]
+            SchemaType::Json => {
+                self.validate_json_schema_draft()?;
+                self.validate_references()
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Validate every schema locally, returning the outcome of each in order
+    pub fn validate_all(schemas: &[UnregisteredSchema]) -> Vec<Result<(), SchemaRegistryError>> {
+        schemas.iter().map(UnregisteredSchema::validate).collect()
+    }
+
+    /// Parse an Avro schema, which can only be done in full when it has no references as the
+    /// named types they define are unknown locally
+    #[cfg(feature = "avro")]
+    fn validate_avro(&self) -> Result<(), SchemaRegistryError> {
+        let result = match self.references.as_deref() {
+            None | Some([]) => apache_avro::Schema::parse_str(&self.schema).map(drop),
+            Some(_) => serde_json::from_str::<serde_json::Value>(&self.schema)
+                .map(drop)
+                .map_err(apache_avro::Error::ParseSchemaJson),
+        };
+
+        result.map_err(|e| SchemaRegistryError::InvalidSchema {
+            message: e.to_string(),
+        })
+    }
+
+    /// Compute the CRC-64-AVRO (Rabin) fingerprint of the schema's Parsing Canonical Form
+    ///
+    /// Schemas that only differ in formatting or in attributes outside the canonical form,
+    /// such as `doc`, share a fingerprint. Only Avro schemas without references can be
+    /// fingerprinted, as the named types references define are unknown locally.
+    #[cfg(feature = "validate-avro")]
+    pub fn fingerprint(&self) -> Result<u64, SchemaRegistryError> {
+        if self.schema_type != SchemaType::Avro {
+            return Err(SchemaRegistryError::InvalidSchema {
+                message: format!("cannot fingerprint a {} schema", self.schema_type),
+            });
+        }
+
+        let schema = apache_avro::Schema::parse_str(&self.schema).map_err(|e| {
+            SchemaRegistryError::InvalidSchema {
+                message: e.to_string(),
+            }
+        })?;
+
+        let fingerprint = schema.fingerprint::<apache_avro::rabin::Rabin>();
+        let bytes = fingerprint
+            .bytes
+            .try_into()
+            .expect("Rabin fingerprints are 8 bytes long");
+
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    /// Check the schema is a JSON object or boolean whose `$schema`, if any, names the draft
+    /// provided with [`UnregisteredSchema::json_schema_draft`]
+    #[cfg(feature = "json-schema")]
+    fn validate_json_schema_draft(&self) -> Result<(), SchemaRegistryError> {
+        let body: serde_json::Value =
+            serde_json::from_str(&self.schema).map_err(|e| SchemaRegistryError::InvalidSchema {
+                message: e.to_string(),
+            })?;
+
+        let declared = match &body {
+            serde_json::Value::Object(map) => map.get("$schema"),
+            serde_json::Value::Bool(_) => None,
+            _ => {
+                return Err(SchemaRegistryError::InvalidSchema {
+                    message: "JSON schema must be an object or a boolean".to_owned(),
+                })
+            }
+        };
+
+        let (Some(draft), Some(declared)) = (self.json_schema_draft, declared) else {
+            return Ok(());
+        };
+
+        match declared.as_str().and_then(JsonSchemaDraft::from_uri) {
+            Some(declared) if declared == draft => Ok(()),
+            _ => Err(SchemaRegistryError::InvalidSchema {
+                message: format!("$schema {} does not match draft {}", declared, draft),
+            }),
+        }
+    }
+
+    /// Check that the declared references match the `$ref`s of a JSON Schema
+    ///
+    /// Every external `$ref` in the schema body must be declared as a [`Reference`] with the
+    /// same name, otherwise an [`SchemaRegistryError::InvalidSchema`] is returned. Declared
+    /// references that no `$ref` points to only produce a warning. Local pointers such as
+    /// `#/definitions/Author` are ignored, as are schemas of any other type.
+    #[cfg(feature = "json-schema")]
+    pub fn validate_references(&self) -> Result<(), SchemaRegistryError> {
+        if self.schema_type != SchemaType::Json {
+            return Ok(());
+        }
+
+        let body: serde_json::Value =
+            serde_json::from_str(&self.schema).map_err(|e| SchemaRegistryError::InvalidSchema {
+                message: e.to_string(),
+            })?;
+
+        let mut refs = std::collections::BTreeSet::new();
+        collect_json_refs(&body, &mut refs);
+
+        let declared: std::collections::BTreeSet<&str> = self
+            .references
+            .iter()
+            .flatten()
+            .map(|reference| reference.name.as_str())
+            .collect();
+
+        if let Some(missing) = refs.iter().find(|r| !declared.contains(r.as_str())) {
+            return Err(SchemaRegistryError::InvalidSchema {
+                message: format!("$ref '{}' has no matching reference", missing),
+            });
+        }
+
+        for unused in declared.iter().filter(|name| !refs.contains(**name)) {
+            tracing::warn!("Reference '{}' is not used by any $ref", unused);
+        }
+
+        Ok(())
+    }
+}
+
+/// Collect the external documents pointed to by `$ref`s, without their fragment.
+#[cfg(feature = "json-schema")]
+fn collect_json_refs(value: &serde_json::Value, refs: &mut std::collections::BTreeSet<String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map {
+                match value {
+                    serde_json::Value::String(target) if key == "$ref" => {
+                        let document = target.split('#').next().unwrap_or_default();
+
+                        if !document.is_empty() {
+                            refs.insert(document.to_owned());
+                        }
+                    }
+                    _ => collect_json_refs(value, refs),
+                }
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for value in values {
+                collect_json_refs(value, refs);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::SchemaRegistryError;
+    #[cfg(feature = "json-schema")]
+    use crate::types::Reference;
+    use crate::types::{
+        schema_equivalent, ClusterId, CompatibilityLevel, CompatibilityResult, ExporterState, Id,
+        JsonSchemaDraft, RegisteredSchema, RuleSet, Schema, SchemaType, ServerVersion, Subject,
+        UnregisteredSchema, Version,
+    };
+
+    #[cfg(feature = "json-schema")]
+    const JSON_BOOK: &str = r##"{
+        "type": "object",
+        "properties": {
+            "title": { "type": "string" },
+            "author": { "$ref": "author.json" },
+            "isbn": { "$ref": "#/definitions/isbn" }
+        },
+        "definitions": { "isbn": { "type": "string" } }
+    }"##;
+
+    #[cfg(feature = "json-schema")]
+    #[test]
+    fn json_schema_ref_with_matching_reference_is_valid() {
+        let schema = UnregisteredSchema::schema(JSON_BOOK)
+            .schema_type(SchemaType::Json)
+            .reference(Reference::new("author.json", "author-value"));
+
+        assert!(schema.validate_references().is_ok());
+    }
+
+    #[cfg(feature = "json-schema")]
+    #[test]
+    fn json_schema_ref_without_reference_is_invalid() {
+        let schema = UnregisteredSchema::schema(JSON_BOOK).schema_type(SchemaType::Json);
+
+        assert!(schema.validate_references().is_err());
+    }
+
+    #[test]
+    fn avro_schema_type_can_be_omitted_from_the_body() {
+        let schema = UnregisteredSchema::schema(r#"{"type":"string"}"#);
+
+        let explicit = serde_json::to_value(&schema).unwrap();
+        let omitted = serde_json::to_value(schema.omit_default_schema_type(true)).unwrap();
+
+        assert_eq!(explicit["schemaType"], "AVRO");
+        assert!(omitted.get("schemaType").is_none());
+        assert_eq!(omitted["schema"], r#"{"type":"string"}"#);
+    }
+
+    #[test]
+    fn non_avro_schema_type_is_kept_when_omitting_the_default() {
+        let schema = UnregisteredSchema::schema(r#"{"type":"object"}"#)
+            .schema_type(SchemaType::Json)
+            .omit_default_schema_type(true);
+
+        let serialized = serde_json::to_value(&schema).unwrap();
+
+        assert_eq!(serialized["schemaType"], "JSON");
+    }
+
+    #[test]
+    fn pretty_indents_avro_schemas() {
+        let schema = Schema {
+            schema_type: SchemaType::Avro,
+            schema: r#"{"type":"record","name":"Book","fields":[]}"#.into(),
+            references: None,
+            metadata: None,
+            rule_set: None,
+        };
+
+        assert_eq!(
+            schema.pretty(),
+            "{\n  \"type\": \"record\",\n  \"name\": \"Book\",\n  \"fields\": []\n}"
+        );
+    }
+
+    #[test]
+    fn pretty_returns_protobuf_and_unparsable_schemas_verbatim() {
+        let protobuf = Schema {
+            schema_type: SchemaType::Protobuf,
+            schema: "syntax = \"proto3\";\nmessage Book { int32 id = 1; }".into(),
+            references: None,
+            metadata: None,
+            rule_set: None,
+        };
+        let broken = Schema {
+            schema_type: SchemaType::Json,
+            schema: "{not json".into(),
+            references: None,
+            metadata: None,
+            rule_set: None,
+        };
+
+        assert_eq!(protobuf.pretty(), protobuf.schema);
+        assert_eq!(broken.pretty(), broken.schema);
+    }
+
+    #[test]
+    fn unknown_schema_types_deserialize_as_other() {
+        let types: Vec<SchemaType> =
+            serde_json::from_str(r#"["AVRO","PROTOBUF","JSON","CUSTOM"]"#).unwrap();
+
+        assert_eq!(
+            types,
+            vec![
+                SchemaType::Avro,
+                SchemaType::Protobuf,
+                SchemaType::Json,
+                SchemaType::Other("CUSTOM".to_owned()),
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&types).unwrap(),
+            r#"["AVRO","PROTOBUF","JSON","CUSTOM"]"#
+        );
+    }
+
+    #[test]
+    fn validate_all_reports_each_schema() {
+        let schemas = [
+            UnregisteredSchema::schema(r#"{"type":"string"}"#),
+            UnregisteredSchema::schema("  "),
+            UnregisteredSchema::schema("syntax = \"proto3\";").schema_type(SchemaType::Protobuf),
+        ];
+
+        let results = UnregisteredSchema::validate_all(&schemas);
+
+        assert!(results[0].is_ok());
+        assert!(matches!(
+            results[1],
+            Err(SchemaRegistryError::InvalidSchema { .. })
+        ));
+        assert!(results[2].is_ok());
+    }
+
+    #[cfg(feature = "avro")]
+    #[test]
+    fn validate_rejects_malformed_avro() {
+        let valid = UnregisteredSchema::schema(
+            r#"{"type":"record","name":"Book","fields":[{"name":"id","type":"int"}]}"#,
+        );
+        let invalid = UnregisteredSchema::schema(r#"{"type":"record","name":"Book"}"#);
+
+        assert!(valid.validate().is_ok());
+        assert!(invalid.validate().is_err());
+    }
+
+    #[cfg(feature = "avro")]
+    #[test]
+    fn validate_rejects_syntactically_broken_avro() {
+        let schema = UnregisteredSchema::schema(r#"{"type":"record","name":"Book","fields":["#);
+
+        assert!(matches!(
+            schema.validate(),
+            Err(SchemaRegistryError::InvalidSchema { .. })
+        ));
+    }
+
+    #[cfg(feature = "validate-avro")]
+    #[test]
+    fn fingerprint_matches_the_avro_specification() {
+        // test vectors from the Avro specification's schema-tests.txt
+        let cases = [
+            (r#""null""#, 7195948357588979594_i64),
+            (r#""int""#, 8247732601305521295),
+            (r#""string""#, -8142146995180207161),
+        ];
+
+        for (schema, expected) in cases {
+            let fingerprint = UnregisteredSchema::schema(schema).fingerprint().unwrap();
+
+            assert_eq!(fingerprint as i64, expected);
+        }
+    }
+
+    #[cfg(feature = "validate-avro")]
+    #[test]
+    fn fingerprint_ignores_formatting() {
+        let compact = UnregisteredSchema::schema(
+            r#"{"type":"record","name":"Book","fields":[{"name":"id","type":"int"}]}"#,
+        );
+        let documented = UnregisteredSchema::schema(
+            r#"{ "type": "record", "name": "Book", "doc": "A book",
+                 "fields": [ { "name": "id", "type": "int" } ] }"#,
+        );
+
+        assert_eq!(
+            compact.fingerprint().unwrap(),
+            documented.fingerprint().unwrap()
+        );
+    }
+
+    #[cfg(feature = "json-schema")]
+    #[test]
+    fn validate_accepts_a_draft_07_json_schema() {
+        let schema = UnregisteredSchema::schema(
+            r#"{
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "type": "object",
+                "properties": { "title": { "type": "string" } }
+            }"#,
+        )
+        .schema_type(SchemaType::Json)
+        .json_schema_draft(JsonSchemaDraft::Draft7);
+
+        assert!(schema.validate().is_ok());
+        assert!(serde_json::to_value(&schema)
+            .unwrap()
+            .get("jsonSchemaDraft")
+            .is_none());
+    }
+
+    #[cfg(feature = "json-schema")]
+    #[test]
+    fn validate_rejects_a_json_schema_of_another_draft() {
+        let schema = UnregisteredSchema::schema(
+            r#"{ "$schema": "https://json-schema.org/draft/2020-12/schema", "type": "object" }"#,
+        )
+        .schema_type(SchemaType::Json)
+        .json_schema_draft(JsonSchemaDraft::Draft7);
+
+        assert!(matches!(
+            schema.validate(),
+            Err(SchemaRegistryError::InvalidSchema { .. })
+        ));
+    }
+
+    #[test]
+    fn json_schema_drafts_are_identified_by_their_uri() {
+        assert_eq!(
+            JsonSchemaDraft::from_uri("https://json-schema.org/draft-07/schema"),
+            Some(JsonSchemaDraft::Draft7)
+        );
+        assert_eq!(
+            JsonSchemaDraft::from_uri(JsonSchemaDraft::Draft2019_09.uri()),
+            Some(JsonSchemaDraft::Draft2019_09)
+        );
+        assert_eq!(JsonSchemaDraft::from_uri("http://example.com/schema"), None);
+    }
+
+    #[cfg(feature = "json-schema")]
+    #[test]
+    fn validate_rejects_malformed_json_schema() {
+        let schema =
+            UnregisteredSchema::schema(r#"{"type": "object""#).schema_type(SchemaType::Json);
+
+        assert!(schema.validate().is_err());
+    }
+
+    #[test]
+    fn ids_deserialize_from_numbers_and_strings() {
+        for body in [r#"{"id":42}"#, r#"{"id":"42"}"#] {
+            let id: Id = serde_json::from_str(body).unwrap();
+            let registered: RegisteredSchema = serde_json::from_str(body).unwrap();
+
+            assert_eq!(id.id, 42);
+            assert_eq!(registered.id, 42);
+        }
+
+        assert!(serde_json::from_str::<Id>(r#"{"id":"forty-two"}"#).is_err());
+    }
+
+    #[test]
+    fn id_and_version_are_serialized_only_when_set() {
+        let schema = UnregisteredSchema::schema(r#""string""#);
+        let imported = schema.clone().with_id(42).with_version(3);
+
+        assert_eq!(
+            serde_json::to_value(&schema).unwrap(),
+            serde_json::json!({ "schema": r#""string""#, "schemaType": "AVRO" })
+        );
+        assert_eq!(
+            serde_json::to_value(&imported).unwrap(),
+            serde_json::json!({
+                "schema": r#""string""#,
+                "schemaType": "AVRO",
+                "id": 42,
+                "version": 3
+            })
+        );
+    }
+
+    #[test]
+    fn exporter_state_is_deserialized_from_its_name() {
+        let cases = [
+            ("STARTING", ExporterState::Starting),
+            ("RUNNING", ExporterState::Running),
+            ("PAUSED", ExporterState::Paused),
+            ("STOPPED", ExporterState::Stopped),
+            ("ERROR", ExporterState::Unknown("ERROR".to_owned())),
+        ];
+
+        for (name, state) in cases {
+            let json = format!(r#""{}""#, name);
+
+            assert_eq!(serde_json::from_str::<ExporterState>(&json).unwrap(), state);
+            assert_eq!(state.to_string(), name);
+            assert_eq!(serde_json::to_string(&state).unwrap(), json);
+        }
+    }
+
+    #[test]
+    fn registered_schema_keeps_the_optional_fields() {
+        let body = r#"{"id":7,"version":3,"subject":"book-value","schema":"\"string\""}"#;
+
+        let registered: RegisteredSchema = serde_json::from_str(body).unwrap();
+
+        assert_eq!(registered.id, 7);
+        assert_eq!(registered.version, Some(3));
+        assert_eq!(registered.subject.as_deref(), Some("book-value"));
+        assert_eq!(registered.schema.as_deref(), Some(r#""string""#));
+    }
+
+    #[test]
+    fn compatibility_result_round_trips_a_verbose_response() {
+        let body = r#"{
+            "is_compatible": false,
+            "messages": [
+                "{errorType:'READER_FIELD_MISSING_DEFAULT_VALUE', description:'The field 'age' at path '/fields/1' in the new schema has no default value and is missing in the old schema', additionalInfo:'age'}",
+                "{oldSchemaVersion: 1}",
+                "{compatibility: 'BACKWARD'}"
+            ]
+        }"#;
+
+        let result: CompatibilityResult = serde_json::from_str(body).unwrap();
+
+        assert!(!result.compatible);
+        assert_eq!(result.messages.len(), 3);
+
+        let serialized = serde_json::to_value(&result).unwrap();
+
+        assert_eq!(serialized["compatible"], false);
+        assert_eq!(serialized["messages"].as_array().unwrap().len(), 3);
+        assert!(serialized.get("is_compatible").is_none());
+
+        let round_trip: CompatibilityResult = serde_json::from_value(serialized).unwrap();
+
+        assert_eq!(round_trip, result);
+    }
+
+    #[cfg(feature = "avro")]
+    #[test]
+    fn avro_schemas_with_reordered_attributes_are_equivalent() {
+        let a = r#"{"type":"record","name":"Author","namespace":"com.example","fields":[{"name":"id","type":"int"}]}"#;
+        let b = r#"{
+            "namespace": "com.example",
+            "fields": [{"type": "int", "name": "id", "doc": "The author id"}],
+            "name": "Author",
+            "type": "record"
+        }"#;
+
+        assert!(schema_equivalent(a, b, SchemaType::Avro).unwrap());
+    }
+
+    #[cfg(feature = "avro")]
+    #[test]
+    fn different_avro_schemas_are_not_equivalent() {
+        let a = r#"{"type":"record","name":"Author","fields":[{"name":"id","type":"int"}]}"#;
+        let b = r#"{"type":"record","name":"Author","fields":[{"name":"id","type":"long"}]}"#;
+
+        assert!(!schema_equivalent(a, b, SchemaType::Avro).unwrap());
+    }
+
+    #[test]
+    fn protobuf_schemas_are_compared_ignoring_whitespace() {
+        let a = "message Author {\n  int64 id = 1;\n}\n";
+        let b = "message Author { int64 id = 1; }";
+        let c = "message Author { int32 id = 1; }";
+
+        assert!(schema_equivalent(a, b, SchemaType::Protobuf).unwrap());
+        assert!(!schema_equivalent(a, c, SchemaType::Protobuf).unwrap());
+    }
+
+    #[test]
+    fn compatibility_levels_parse_case_insensitively() {
+        for (input, level) in [
+            ("BACKWARD", CompatibilityLevel::Backward),
+            (
+                "backward_transitive",
+                CompatibilityLevel::BackwardTransitive,
+            ),
+            ("Forward", CompatibilityLevel::Forward),
+            ("FORWARD_TRANSITIVE", CompatibilityLevel::ForwardTransitive),
+            ("full", CompatibilityLevel::Full),
+            ("FULL_TRANSITIVE", CompatibilityLevel::FullTransitive),
+            ("none", CompatibilityLevel::None),
+        ] {
+            assert_eq!(input.parse::<CompatibilityLevel>().unwrap(), level);
+            assert_eq!(
+                level.to_string().parse::<CompatibilityLevel>().unwrap(),
+                level
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_compatibility_level_is_rejected() {
+        let result = "SIDEWAYS".parse::<CompatibilityLevel>();
+
+        assert!(matches!(
+            result,
+            Err(SchemaRegistryError::InvalidCompatibilityLevel { .. })
+        ));
+        assert!(serde_json::from_str::<CompatibilityLevel>(r#""SIDEWAYS""#).is_err());
+    }
+
+    #[test]
+    fn data_contract_round_trips() {
+        let payload = serde_json::json!({
+            "schemaType": "AVRO",
+            "schema": "{\"type\":\"record\",\"name\":\"Order\",\"fields\":[]}",
+            "references": null,
+            "metadata": {
+                "tags": { "Order.email": ["PII"] },
+                "properties": { "owner": "orders-team" },
+                "sensitive": ["owner"]
+            },
+            "ruleSet": {
+                "domainRules": [{
+                    "name": "checkTotal",
+                    "kind": "CONDITION",
+                    "mode": "WRITE",
+                    "type": "CEL",
+                    "expr": "message.total > 0",
+                    "onFailure": "DLQ"
+                }],
+                "migrationRules": []
+            }
+        });
+
+        let schema: Schema = serde_json::from_value(payload.clone()).unwrap();
+
+        let metadata = schema.metadata.as_ref().unwrap();
+        assert_eq!(metadata.tags["Order.email"], vec!["PII"]);
+        assert_eq!(metadata.properties["owner"], "orders-team");
+        assert_eq!(metadata.sensitive, vec!["owner"]);
+
+        let rule = &schema.rule_set.as_ref().unwrap().domain_rules[0];
+        assert_eq!(rule.name, "checkTotal");
+        assert_eq!(rule.kind, "CONDITION");
+        assert_eq!(rule.rule_type, "CEL");
+        assert_eq!(rule.expr.as_deref(), Some("message.total > 0"));
+        assert_eq!(rule.on_success, None);
+        assert_eq!(rule.on_failure.as_deref(), Some("DLQ"));
+
+        assert_eq!(serde_json::to_value(&schema).unwrap(), payload);
+    }
+
+    #[test]
+    fn data_contract_fields_are_optional() {
+        let subject: Subject = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "subject": "orders-value",
+            "version": 1,
+            "schema": "\"string\"",
+            "metadata": { "properties": { "owner": "orders-team" } },
+            "ruleSet": {}
+        }))
+        .unwrap();
+
+        assert_eq!(subject.metadata.unwrap().properties["owner"], "orders-team");
+        assert_eq!(subject.rule_set, Some(RuleSet::default()));
+
+        let schema: Schema =
+            serde_json::from_value(serde_json::json!({ "schema": "\"string\"" })).unwrap();
+
+        assert!(schema.metadata.is_none());
+        assert!(schema.rule_set.is_none());
+    }
+
+    #[test]
+    fn subjects_are_compared_by_value() {
+        let subject = Subject {
+            id: 2,
+            subject: "book-value".to_owned(),
+            version: 1,
+            schema_type: SchemaType::Avro,
+            schema: r#""string""#.into(),
+            references: None,
+            metadata: None,
+            rule_set: None,
+        };
+        let newer = Subject {
+            id: 3,
+            version: 2,
+            ..subject.clone()
+        };
+
+        assert_eq!(subject, subject.clone());
+        assert_ne!(subject, newer);
+    }
+
+    #[test]
+    fn cluster_id_is_deserialized() {
+        let json = r#"{
+            "scope": {
+                "path": [],
+                "clusters": {
+                    "kafka-cluster": "MkVlNjdqWVF0Q056MWFrUA",
+                    "schema-registry-cluster": "schema-registry"
+                }
+            },
+            "id": "MkVlNjdqWVF0Q056MWFrUA"
+        }"#;
+
+        let cluster_id: ClusterId = serde_json::from_str(json).unwrap();
+
+        assert_eq!(cluster_id.id, "MkVlNjdqWVF0Q056MWFrUA");
+        assert!(cluster_id.scope.path.is_empty());
+        assert_eq!(
+            cluster_id.scope.clusters["schema-registry-cluster"],
+            "schema-registry"
+        );
+    }
+
+    #[test]
+    fn server_version_is_deserialized() {
+        let json = r#"{ "version": "7.6.0", "commitId": "1b5f4a2c9e3d7f80" }"#;
+
+        let version: ServerVersion = serde_json::from_str(json).unwrap();
+
+        assert_eq!(version.version, "7.6.0");
+        assert_eq!(version.commit_id, "1b5f4a2c9e3d7f80");
+    }
+
+    #[test]
+    fn version_is_parsed_from_its_string_form() {
+        assert_eq!("latest".parse::<Version>().unwrap(), Version::Latest);
+        assert_eq!("LATEST".parse::<Version>().unwrap(), Version::Latest);
+        assert_eq!("3".parse::<Version>().unwrap(), Version::Number(3));
+        assert!(matches!(
+            "0".parse::<Version>(),
+            Err(SchemaRegistryError::InvalidVersion { .. })
+        ));
+        assert!("-1".parse::<Version>().is_err());
+        assert!("newest".parse::<Version>().is_err());
+    }
+
+    #[test]
+    fn version_round_trips_through_serde() {
+        assert_eq!(
+            serde_json::to_string(&Version::Latest).unwrap(),
+            r#""latest""#
+        );
+        assert_eq!(
+            serde_json::to_string(&Version::Number(3)).unwrap(),
+            r#""3""#
+        );
+
+        assert_eq!(
+            serde_json::from_str::<Version>(r#""latest""#).unwrap(),
+            Version::Latest
+        );
+        assert_eq!(
+            serde_json::from_str::<Version>(r#""3""#).unwrap(),
+            Version::Number(3)
+        );
+        assert_eq!(
+            serde_json::from_str::<Version>("3").unwrap(),
+            Version::Number(3)
+        );
+        assert!(serde_json::from_str::<Version>(r#""0""#).is_err());
+        assert!(serde_json::from_str::<Version>("0").is_err());
+        assert!(serde_json::from_str::<Version>("-2").is_err());
+    }
+
+    #[test]
+    fn version_number_rejects_zero() {
+        assert_eq!(Version::number(1).unwrap(), Version::Number(1));
+        assert!(Version::number(0).is_err());
+    }
 }
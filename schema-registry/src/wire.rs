@@ -0,0 +1,153 @@
+//! Framing of Kafka record payloads in the Schema Registry wire format.
+//!
+//! Every framed payload starts with a zero magic byte followed by the big-endian schema id,
+//! the remaining bytes being the payload produced by the format-specific serializer.
+
+use crate::error::{BoxError, SchemaRegistryError};
+
+/// The magic byte that starts every framed payload
+pub const MAGIC_BYTE: u8 = 0;
+
+const HEADER_LEN: usize = 5;
+
+/// Prepend the wire format framing for `schema_id` to a payload
+pub fn encode_wire_format(schema_id: u32, payload: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(HEADER_LEN + payload.len());
+    bytes.push(MAGIC_BYTE);
+    bytes.extend_from_slice(&schema_id.to_be_bytes());
+    bytes.extend_from_slice(payload);
+
+    bytes
+}
+
+/// Split a framed payload into its schema id and the payload that follows the framing
+pub fn decode_wire_format(bytes: &[u8]) -> Result<(u32, &[u8]), SchemaRegistryError> {
+    if bytes.len() < HEADER_LEN {
+        return Err(SchemaRegistryError::InvalidWireFormat {
+            message: format!(
+                "expected at least {} bytes, got {}",
+                HEADER_LEN,
+                bytes.len()
+            ),
+        });
+    }
+
+    if bytes[0] != MAGIC_BYTE {
+        return Err(SchemaRegistryError::InvalidWireFormat {
+            message: format!("unknown magic byte {}", bytes[0]),
+        });
+    }
+
+    let schema_id = u32::from_be_bytes([bytes[1], bytes[2], bytes[3], bytes[4]]);
+
+    Ok((schema_id, &bytes[HEADER_LEN..]))
+}
+
+/// Serialize a value with `f` and prepend the wire format framing for `schema_id`
+pub fn serialize_with<T, F, E>(
+    schema_id: u32,
+    value: &T,
+    f: F,
+) -> Result<Vec<u8>, SchemaRegistryError>
+where
+    T: ?Sized,
+    F: FnOnce(&T) -> Result<Vec<u8>, E>,
+    E: Into<BoxError>,
+{
+    let payload = f(value).map_err(|e| SchemaRegistryError::Other(e.into()))?;
+
+    Ok(encode_wire_format(schema_id, &payload))
+}
+
+/// Strip the wire format framing and hand the schema id and payload to `f`
+pub fn deserialize_with<T, F, E>(bytes: &[u8], f: F) -> Result<T, SchemaRegistryError>
+where
+    F: FnOnce(u32, &[u8]) -> Result<T, E>,
+    E: Into<BoxError>,
+{
+    let (schema_id, payload) = decode_wire_format(bytes)?;
+
+    f(schema_id, payload).map_err(|e| SchemaRegistryError::Other(e.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::error::SchemaRegistryError;
+    use crate::wire::{decode_wire_format, deserialize_with, encode_wire_format, serialize_with};
+
+    #[test]
+    fn identity_round_trip_preserves_schema_id_and_payload() {
+        let payload = b"hello".to_vec();
+
+        let framed = serialize_with(42, &payload, |p| Ok::<_, Infallible>(p.clone())).unwrap();
+
+        assert_eq!(framed, [0, 0, 0, 0, 42, b'h', b'e', b'l', b'l', b'o']);
+
+        let (schema_id, decoded) =
+            deserialize_with(&framed, |id, p| Ok::<_, Infallible>((id, p.to_vec()))).unwrap();
+
+        assert_eq!(schema_id, 42);
+        assert_eq!(decoded, payload);
+    }
+
+    #[test]
+    fn deserialize_rejects_unknown_magic_byte() {
+        let result = deserialize_with(&[1, 0, 0, 0, 42], |id, _| Ok::<_, Infallible>(id));
+
+        assert!(matches!(
+            result,
+            Err(SchemaRegistryError::InvalidWireFormat { .. })
+        ));
+    }
+
+    #[test]
+    fn deserialize_rejects_truncated_header() {
+        let result = deserialize_with(&[0, 0, 0], |id, _| Ok::<_, Infallible>(id));
+
+        assert!(matches!(
+            result,
+            Err(SchemaRegistryError::InvalidWireFormat { .. })
+        ));
+    }
+
+    #[test]
+    fn encoded_payload_decodes_to_the_same_id_and_payload() {
+        let framed = encode_wire_format(0x0102_0304, b"payload");
+
+        assert_eq!(&framed[..5], &[0, 1, 2, 3, 4]);
+
+        let (schema_id, payload) = decode_wire_format(&framed).unwrap();
+
+        assert_eq!(schema_id, 0x0102_0304);
+        assert_eq!(payload, b"payload");
+    }
+
+    #[test]
+    fn empty_payload_round_trips() {
+        let framed = encode_wire_format(7, &[]);
+
+        assert_eq!(decode_wire_format(&framed).unwrap(), (7, &[][..]));
+    }
+
+    #[test]
+    fn decode_rejects_missing_magic_byte() {
+        let result = decode_wire_format(&[0x7b, 0, 0, 0, 1, 2]);
+
+        assert!(matches!(
+            result,
+            Err(SchemaRegistryError::InvalidWireFormat { .. })
+        ));
+    }
+
+    #[test]
+    fn decode_rejects_short_buffer() {
+        for bytes in [&[][..], &[0, 0, 0, 1][..]] {
+            assert!(matches!(
+                decode_wire_format(bytes),
+                Err(SchemaRegistryError::InvalidWireFormat { .. })
+            ));
+        }
+    }
+}
@@ -0,0 +1,179 @@
+#![cfg(feature = "avro")]
+
+use apache_avro::types::Value;
+use schema_registry::api::SchemaRegistryAPI;
+use schema_registry::client::SchemaRegistryClient;
+use schema_registry::error::SchemaRegistryError;
+use schema_registry::types::UnregisteredSchema;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const AUTHOR: &str = r#"{"type":"record","name":"Author","namespace":"com.example","fields":[{"name":"name","type":"string"}]}"#;
+const BOOK: &str = r#"{"type":"record","name":"Book","namespace":"com.example","fields":[{"name":"id","type":"int"},{"name":"author","type":"com.example.Author"}]}"#;
+
+#[tokio::test]
+async fn avro_schema_is_parsed_with_its_references() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/schemas/ids/2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "schema": BOOK,
+            "references": [
+                { "name": "com.example.Author", "subject": "author-value", "version": 1 }
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/author-value/versions/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 1,
+            "subject": "author-value",
+            "version": 1,
+            "schema": AUTHOR
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let schema = client.get_avro_schema_by_id(2).await.unwrap();
+
+    let apache_avro::Schema::Record(record) = schema else {
+        panic!("expected a record schema, got {:?}", schema);
+    };
+
+    assert_eq!(record.name.fullname(None), "com.example.Book");
+    assert_eq!(record.fields.len(), 2);
+}
+
+#[tokio::test]
+async fn non_avro_schema_is_rejected() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/schemas/ids/3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "schemaType": "PROTOBUF",
+            "schema": "syntax = \"proto3\";"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let result = client.get_avro_schema_by_id(3).await;
+
+    assert!(matches!(
+        result,
+        Err(SchemaRegistryError::InvalidSchemaType { .. })
+    ));
+}
+
+#[tokio::test]
+async fn removing_a_required_field_suggests_a_default() {
+    let server = MockServer::start().await;
+
+    let latest = r#"{"type":"record","name":"Book","fields":[{"name":"id","type":"int"},{"name":"title","type":"string"}]}"#;
+    let proposed = r#"{"type":"record","name":"Book","fields":[{"name":"id","type":"int"}]}"#;
+
+    Mock::given(method("POST"))
+        .and(path("/compatibility/subjects/book-value/versions/latest"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "is_compatible": false,
+            "messages": [
+                "{errorType:'READER_FIELD_MISSING_DEFAULT_VALUE', description:'The field 'title' at path '/fields/1' in the old schema has no default value and is missing in the new schema', additionalInfo:'title'}",
+                "{compatibility: 'FORWARD'}"
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions/latest"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 1,
+            "subject": "book-value",
+            "version": 1,
+            "schema": latest
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let suggestions = client
+        .suggest_compatible_changes("book-value", &UnregisteredSchema::schema(proposed))
+        .await
+        .unwrap();
+
+    assert_eq!(suggestions.len(), 1);
+    assert!(suggestions[0].contains("'title'"));
+    assert!(suggestions[0].contains("default"));
+}
+
+#[tokio::test]
+async fn avro_serializer_frames_encoded_records() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/author-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 3 })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let serialize = client
+        .avro_serializer("author-value", &UnregisteredSchema::schema(AUTHOR), false)
+        .await
+        .unwrap();
+
+    let record = Value::Record(vec![("name".to_owned(), Value::String("Ada".to_owned()))]);
+    let framed = serialize(&record).unwrap();
+
+    assert_eq!(framed, [0, 0, 0, 0, 3, 6, b'A', b'd', b'a']);
+}
+
+#[tokio::test]
+async fn avro_deserializer_decodes_serialized_records() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/author-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 3 })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/schemas/ids/3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "schema": AUTHOR })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let serialize = client
+        .avro_serializer("author-value", &UnregisteredSchema::schema(AUTHOR), false)
+        .await
+        .unwrap();
+    let deserialize = client.avro_deserializer();
+
+    for name in ["Ada", "Grace"] {
+        let record = Value::Record(vec![("name".to_owned(), Value::String(name.to_owned()))]);
+        let framed = serialize(&record).unwrap();
+
+        let decoded = deserialize(&framed).await.unwrap();
+
+        assert_eq!(decoded, record);
+    }
+}
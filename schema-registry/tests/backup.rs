@@ -0,0 +1,306 @@
+use schema_registry::api::SchemaRegistryAPI;
+use schema_registry::backup::{
+    ImportOutcome, ImportedVersion, RegistrySnapshot, SnapshotSetting, SnapshotSubject,
+};
+use schema_registry::client::SchemaRegistryClient;
+use schema_registry::types::{
+    ClusterConfig, CompatibilityLevel, Mode, Reference, SchemaType, SubjectConfig,
+};
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const AUTHOR: &str =
+    r#"{"type":"record","name":"Author","fields":[{"name":"name","type":"string"}]}"#;
+const BOOK: &str =
+    r#"{"type":"record","name":"Book","fields":[{"name":"author","type":"Author"}]}"#;
+
+/// Mount a registry holding `author-value` and `book-value`, the latter referencing the former.
+async fn mount_registry(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/config"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "compatibilityLevel": "BACKWARD" })),
+        )
+        .mount(server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/mode"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "mode": "READWRITE" })))
+        .mount(server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .and(query_param("deleted", "false"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!(["book-value", "author-value"])),
+        )
+        .mount(server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/config/book-value"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "compatibilityLevel": "FULL" })),
+        )
+        .mount(server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/author-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([1])))
+        .mount(server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/author-value/versions/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "subject": "author-value",
+            "id": 1,
+            "version": 1,
+            "schema": AUTHOR
+        })))
+        .mount(server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([1, 2])))
+        .mount(server)
+        .await;
+
+    for (version, id) in [(1, 2), (2, 3)] {
+        Mock::given(method("GET"))
+            .and(path(format!("/subjects/book-value/versions/{}", version)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "subject": "book-value",
+                "id": id,
+                "version": version,
+                "schema": BOOK,
+                "references": [{ "name": "Author", "subject": "author-value", "version": 1 }]
+            })))
+            .mount(server)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn export_all_captures_every_subject_version() {
+    let server = MockServer::start().await;
+    mount_registry(&server).await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let snapshot = client.export_all(false).await.unwrap();
+
+    assert_eq!(
+        snapshot.config,
+        Some(ClusterConfig::new().compatibility_level(CompatibilityLevel::Backward))
+    );
+    assert_eq!(snapshot.mode, Some(Mode::ReadWrite));
+
+    let subjects: Vec<_> = snapshot.subjects.keys().map(String::as_str).collect();
+    assert_eq!(subjects, ["author-value", "book-value"]);
+
+    let author = &snapshot.subjects["author-value"];
+    assert_eq!(author.config, None);
+    assert_eq!(author.mode, None);
+    assert_eq!(author.versions[&1].id, 1);
+    assert_eq!(author.versions[&1].schema.schema, AUTHOR);
+
+    let book = &snapshot.subjects["book-value"];
+    assert_eq!(
+        book.config,
+        Some(SubjectConfig::new().compatibility_level(CompatibilityLevel::Full))
+    );
+    assert_eq!(book.versions.keys().copied().collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(book.versions[&2].id, 3);
+    assert_eq!(book.versions[&2].schema.schema_type, SchemaType::Avro);
+    assert_eq!(
+        book.versions[&2].schema.references,
+        Some(vec![Reference::new("Author", "author-value").version(1)])
+    );
+}
+
+#[tokio::test]
+async fn export_all_serializes_deterministically() {
+    let server = MockServer::start().await;
+    mount_registry(&server).await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let first = serde_json::to_string(&client.export_all(false).await.unwrap()).unwrap();
+    let second = serde_json::to_string(&client.export_all(false).await.unwrap()).unwrap();
+
+    assert_eq!(first, second);
+    assert!(first.find("author-value").unwrap() < first.find("book-value").unwrap());
+}
+
+#[tokio::test]
+async fn import_all_restores_referenced_subjects_first() {
+    let source = MockServer::start().await;
+    mount_registry(&source).await;
+
+    let snapshot = SchemaRegistryClient::from_url(&source.uri())
+        .unwrap()
+        .export_all(false)
+        .await
+        .unwrap();
+
+    let target = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/mode"))
+        .and(query_param("force", "true"))
+        .and(body_partial_json(json!({ "mode": "IMPORT" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "mode": "IMPORT" })))
+        .expect(1)
+        .mount(&target)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/mode"))
+        .and(body_partial_json(json!({ "mode": "READWRITE" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "mode": "READWRITE" })))
+        .expect(1)
+        .mount(&target)
+        .await;
+
+    // author-value is already in the target registry
+    Mock::given(method("GET"))
+        .and(path("/subjects/author-value/versions/1"))
+        .and(query_param("deleted", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "subject": "author-value",
+            "id": 1,
+            "version": 1,
+            "schema": AUTHOR
+        })))
+        .mount(&target)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions/1"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error_code": 40401,
+            "message": "Subject 'book-value' not found."
+        })))
+        .mount(&target)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions/2"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error_code": 40402,
+            "message": "Version 2 not found."
+        })))
+        .mount(&target)
+        .await;
+
+    for (version, id) in [(1, 2), (2, 3)] {
+        Mock::given(method("POST"))
+            .and(path("/subjects/book-value/versions"))
+            .and(body_partial_json(json!({
+                "schema": BOOK,
+                "id": id,
+                "version": version,
+                "references": [{ "name": "Author", "subject": "author-value", "version": 1 }]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": id })))
+            .expect(1)
+            .mount(&target)
+            .await;
+    }
+
+    Mock::given(method("PUT"))
+        .and(path("/config"))
+        .and(body_partial_json(json!({ "compatibility": "BACKWARD" })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "compatibility": "BACKWARD" })),
+        )
+        .expect(1)
+        .mount(&target)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/config/book-value"))
+        .and(body_partial_json(json!({ "compatibility": "FULL" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "compatibility": "FULL" })))
+        .expect(1)
+        .mount(&target)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&target.uri()).unwrap();
+
+    let report = client.import_all(&snapshot).await.unwrap();
+
+    let imported = |subject: &str, version, outcome| ImportedVersion {
+        subject: subject.to_owned(),
+        version,
+        outcome,
+    };
+
+    assert!(report.is_success());
+    assert_eq!(
+        report.versions,
+        [
+            imported("author-value", 1, ImportOutcome::Skipped),
+            imported("book-value", 1, ImportOutcome::Imported),
+            imported("book-value", 2, ImportOutcome::Imported),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn import_all_resets_the_global_mode_when_a_config_fails() {
+    let mut snapshot = RegistrySnapshot::default();
+    snapshot.subjects.insert(
+        "book-value".to_owned(),
+        SnapshotSubject {
+            config: Some(SubjectConfig::new().compatibility_level(CompatibilityLevel::Full)),
+            ..SnapshotSubject::default()
+        },
+    );
+
+    let target = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/mode"))
+        .and(body_partial_json(json!({ "mode": "IMPORT" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "mode": "IMPORT" })))
+        .expect(1)
+        .mount(&target)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/config/book-value"))
+        .respond_with(ResponseTemplate::new(500).set_body_json(json!({
+            "error_code": 50001,
+            "message": "Error in the backend datastore"
+        })))
+        .expect(1)
+        .mount(&target)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/mode"))
+        .and(body_partial_json(json!({ "mode": "READWRITE" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "mode": "READWRITE" })))
+        .expect(1)
+        .mount(&target)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&target.uri()).unwrap();
+
+    let report = client.import_all(&snapshot).await.unwrap();
+
+    assert!(!report.is_success());
+    assert_eq!(report.failed_settings.len(), 1);
+    assert_eq!(
+        report.failed_settings[0].subject.as_deref(),
+        Some("book-value")
+    );
+    assert_eq!(report.failed_settings[0].setting, SnapshotSetting::Config);
+}
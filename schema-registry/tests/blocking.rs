@@ -0,0 +1,110 @@
+#![cfg(feature = "blocking")]
+
+use futures::executor::block_on;
+use schema_registry::blocking::BlockingSchemaRegistryClient;
+use schema_registry::client::config::SchemaRegistryConfig;
+use schema_registry::types::{UnregisteredSchema, Version};
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn schema_is_registered_and_fetched_without_a_runtime() {
+    let server = block_on(MockServer::start());
+
+    block_on(
+        Mock::given(method("POST"))
+            .and(path("/subjects/book-value/versions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 1 })))
+            .expect(1)
+            .mount(&server),
+    );
+
+    block_on(
+        Mock::given(method("GET"))
+            .and(path("/subjects/book-value/versions/latest"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "subject": "book-value",
+                "id": 1,
+                "version": 1,
+                "schema": r#"{"type":"string"}"#
+            })))
+            .expect(1)
+            .mount(&server),
+    );
+
+    let config = SchemaRegistryConfig::new().url(server.uri());
+    let client = BlockingSchemaRegistryClient::from_conf(config).unwrap();
+    let schema = UnregisteredSchema::schema(r#"{"type":"string"}"#);
+
+    let id = client
+        .post_new_subject_version("book-value", &schema, false)
+        .unwrap();
+    let subject = client
+        .get_subject_version("book-value", Version::Latest)
+        .unwrap();
+
+    assert_eq!(id, 1);
+    assert_eq!(subject.id, 1);
+    assert_eq!(subject.schema, r#"{"type":"string"}"#);
+}
+
+#[test]
+fn upstream_errors_are_returned() {
+    let server = block_on(MockServer::start());
+
+    block_on(
+        Mock::given(method("GET"))
+            .and(path("/schemas/ids/7"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+                "error_code": 40403,
+                "message": "Schema 7 not found"
+            })))
+            .mount(&server),
+    );
+
+    let config = SchemaRegistryConfig::new().url(server.uri());
+    let client = BlockingSchemaRegistryClient::from_conf(config).unwrap();
+
+    let error = client.get_schema_by_id(7).unwrap_err();
+
+    assert!(error.is_schema_not_found());
+}
+
+#[test]
+fn default_methods_are_available_without_a_runtime() {
+    let server = block_on(MockServer::start());
+
+    block_on(
+        Mock::given(method("GET"))
+            .and(path("/subjects"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!(["book-value"])))
+            .mount(&server),
+    );
+
+    block_on(
+        Mock::given(method("GET"))
+            .and(path("/subjects/book-value/versions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([1])))
+            .mount(&server),
+    );
+
+    block_on(
+        Mock::given(method("GET"))
+            .and(path("/subjects/book-value/versions/1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "subject": "book-value",
+                "id": 4,
+                "version": 1,
+                "schema": r#"{"type":"string"}"#
+            })))
+            .mount(&server),
+    );
+
+    let config = SchemaRegistryConfig::new().url(server.uri());
+    let client = BlockingSchemaRegistryClient::from_conf(config).unwrap();
+
+    let index = client.full_index().unwrap();
+
+    assert_eq!(index, vec![("book-value".to_string(), 1, 4)]);
+}
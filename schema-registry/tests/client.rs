@@ -0,0 +1,914 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use http::HeaderValue;
+
+use schema_registry::api::SchemaRegistryAPI;
+use schema_registry::client::config::{AuthProvider, EndpointStrategy, SchemaRegistryConfig};
+use schema_registry::client::retry::{RetryInfo, RetryPolicy};
+use schema_registry::client::SchemaRegistryClient;
+use schema_registry::error::{ConfigurationError, SchemaRegistryError};
+use schema_registry::types::{AttemptOutcome, RegistryFlavor, UnregisteredSchema};
+use serde_json::json;
+use tracing_test::traced_test;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn client_reports_configured_urls() {
+    let config = SchemaRegistryConfig::new().url("http://localhost:8081,http://localhost:8082");
+
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    assert_eq!(
+        client.urls(),
+        ["http://localhost:8081", "http://localhost:8082"]
+    );
+}
+
+#[tokio::test]
+async fn pinned_url_is_the_only_one_contacted() {
+    let first = MockServer::start().await;
+    let second = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["book-value"])))
+        .expect(0)
+        .mount(&first)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["book-value"])))
+        .expect(1)
+        .mount(&second)
+        .await;
+
+    let config = SchemaRegistryConfig::new()
+        .url(first.uri())
+        .url(second.uri());
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    let pinned = client.at_url(&second.uri()).unwrap();
+    let subjects = pinned.get_subjects(false).await.unwrap();
+
+    assert_eq!(pinned.urls(), [second.uri()]);
+    assert_eq!(subjects, vec!["book-value"]);
+}
+
+#[test]
+fn pinning_unknown_url_fails() {
+    let client = SchemaRegistryClient::from_url("http://localhost:8081").unwrap();
+
+    let result = client.at_url("http://localhost:9999");
+
+    assert!(matches!(
+        result,
+        Err(SchemaRegistryError::Configuration(
+            ConfigurationError::UnknownUrl { .. }
+        ))
+    ));
+}
+
+#[tokio::test]
+async fn api_key_header_is_sent() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .and(header("X-API-Key", "sr-api-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let key = "sr-api-key".to_owned();
+    let config = SchemaRegistryConfig::new()
+        .url(server.uri())
+        .api_key_auth("X-API-Key", &key);
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    client.get_subjects(false).await.unwrap();
+}
+
+struct RotatingToken {
+    calls: AtomicUsize,
+}
+
+#[async_trait]
+impl AuthProvider for RotatingToken {
+    async fn header(&self) -> Result<HeaderValue, SchemaRegistryError> {
+        let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+
+        Ok(HeaderValue::from_str(&format!("Bearer token-{}", call)).unwrap())
+    }
+}
+
+#[tokio::test]
+async fn auth_provider_is_asked_for_every_request() {
+    let server = MockServer::start().await;
+
+    for token in ["Bearer token-1", "Bearer token-2"] {
+        Mock::given(method("GET"))
+            .and(path("/subjects"))
+            .and(header("authorization", token))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let config = SchemaRegistryConfig::new()
+        .url(server.uri())
+        .auth_provider(Arc::new(RotatingToken {
+            calls: AtomicUsize::new(0),
+        }));
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    client.get_subjects(false).await.unwrap();
+    client.get_subjects(false).await.unwrap();
+}
+
+#[tokio::test]
+async fn warm_up_contacts_every_configured_url_once() {
+    let first = MockServer::start().await;
+    let second = MockServer::start().await;
+
+    for server in [&first, &second] {
+        Mock::given(method("GET"))
+            .and(path("/config"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "compatibilityLevel": "BACKWARD" })),
+            )
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    let config = SchemaRegistryConfig::new()
+        .url(first.uri())
+        .url(second.uri());
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    client.warm_up().await.unwrap();
+}
+
+#[tokio::test]
+async fn failed_url_is_skipped_during_cooldown() {
+    let dead = MockServer::start().await;
+    let alive = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&dead)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!(["book-value"]))
+                .set_delay(Duration::from_millis(200)),
+        )
+        .expect(2)
+        .mount(&alive)
+        .await;
+
+    let config = SchemaRegistryConfig::new()
+        .url(dead.uri())
+        .url(alive.uri())
+        .url_health_tracking(true);
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    for _ in 0..2 {
+        let subjects = client.get_subjects(false).await.unwrap();
+
+        assert_eq!(subjects, vec!["book-value"]);
+    }
+}
+
+#[tokio::test]
+async fn redpanda_is_detected_from_server_header() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("server", "Seastar httpd")
+                .set_body_json(json!({})),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let flavor = client.detect_flavor().await.unwrap();
+
+    assert_eq!(flavor, RegistryFlavor::Redpanda);
+}
+
+#[tokio::test]
+async fn confluent_is_detected_from_metadata_endpoint() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/metadata/id"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "scope": {
+                "path": [],
+                "clusters": {
+                    "kafka-cluster": "MkVlNjdqWVF0Q056MWFrUA",
+                    "schema-registry-cluster": "schema-registry"
+                }
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let flavor = client.detect_flavor().await.unwrap();
+
+    assert_eq!(flavor, RegistryFlavor::Confluent);
+}
+
+#[tokio::test]
+async fn server_metadata_is_fetched() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/metadata/id"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "scope": { "path": [], "clusters": { "kafka-cluster": "lkc-1" } },
+            "id": "lkc-1"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/metadata/version"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "version": "7.6.0", "commitId": "1b5f4a2c" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let cluster_id = client.get_cluster_id().await.unwrap();
+    let version = client.get_server_version().await.unwrap();
+
+    assert_eq!(cluster_id.id, "lkc-1");
+    assert_eq!(cluster_id.scope.clusters["kafka-cluster"], "lkc-1");
+    assert_eq!(version.version, "7.6.0");
+    assert_eq!(version.commit_id, "1b5f4a2c");
+}
+
+#[tokio::test]
+async fn failover_stops_after_max_attempts() {
+    let servers = [
+        MockServer::start().await,
+        MockServer::start().await,
+        MockServer::start().await,
+    ];
+
+    for (server, expected) in servers.iter().zip([1, 1, 0]) {
+        Mock::given(method("GET"))
+            .and(path("/subjects"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(expected)
+            .mount(server)
+            .await;
+    }
+
+    let config = servers
+        .iter()
+        .fold(SchemaRegistryConfig::new(), |config, server| {
+            config.url(server.uri())
+        })
+        .max_failover_attempts(2);
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    let result = client.get_subjects(false).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn failover_report_lists_each_url_outcome() {
+    let dead = MockServer::start().await;
+    let alive = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&dead)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!(["book-value"]))
+                .set_delay(Duration::from_millis(200)),
+        )
+        .mount(&alive)
+        .await;
+
+    let config = SchemaRegistryConfig::new().url(dead.uri()).url(alive.uri());
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    let (subjects, report) = client
+        .with_failover_report(|client| async move { client.get_subjects(false).await })
+        .await
+        .unwrap();
+
+    assert_eq!(subjects, vec!["book-value"]);
+    assert_eq!(report.attempts.len(), 2);
+    assert!(report.attempts[0].url.starts_with(&dead.uri()));
+    assert!(matches!(
+        report.attempts[0].outcome,
+        AttemptOutcome::Failed { .. }
+    ));
+    assert!(report.attempts[1].url.starts_with(&alive.uri()));
+    assert_eq!(report.attempts[1].outcome, AttemptOutcome::Succeeded);
+}
+
+#[tokio::test]
+async fn explicit_charset_is_sent_on_write_calls() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/book-value/versions"))
+        .and(header(
+            "content-type",
+            "application/vnd.schemaregistry.v1+json; charset=utf-8",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 7 })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let config = SchemaRegistryConfig::new()
+        .url(server.uri())
+        .explicit_charset(true);
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+    let schema = UnregisteredSchema::schema(r#"{"type":"string"}"#);
+
+    let id = client
+        .post_new_subject_version("book-value", &schema, false)
+        .await
+        .unwrap();
+
+    assert_eq!(id, 7);
+}
+
+#[tokio::test]
+async fn mutating_calls_fail_over_sequentially() {
+    let dead = MockServer::start().await;
+    let alive = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/book-value/versions"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&dead)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/book-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 7 })))
+        .expect(1)
+        .mount(&alive)
+        .await;
+
+    let config = SchemaRegistryConfig::new().url(dead.uri()).url(alive.uri());
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+    let schema = UnregisteredSchema::schema(r#"{"type":"string"}"#);
+
+    let id = client
+        .post_new_subject_version("book-value", &schema, false)
+        .await
+        .unwrap();
+
+    assert_eq!(id, 7);
+}
+
+#[tokio::test]
+async fn mutating_calls_stop_at_the_first_success() {
+    let first = MockServer::start().await;
+    let second = MockServer::start().await;
+
+    for (server, expected) in [(&first, 1), (&second, 0)] {
+        Mock::given(method("DELETE"))
+            .and(path("/subjects/book-value"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([1])))
+            .expect(expected)
+            .mount(server)
+            .await;
+    }
+
+    let config = SchemaRegistryConfig::new()
+        .url(first.uri())
+        .url(second.uri());
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    client.delete_subject("book-value", false).await.unwrap();
+}
+
+#[tokio::test]
+async fn mutating_calls_do_not_fail_over_on_client_errors() {
+    let first = MockServer::start().await;
+    let second = MockServer::start().await;
+
+    for (server, expected) in [(&first, 1), (&second, 0)] {
+        Mock::given(method("POST"))
+            .and(path("/subjects/book-value/versions"))
+            .respond_with(ResponseTemplate::new(409).set_body_json(json!({
+                "error_code": 409,
+                "message": "Schema being registered is incompatible with an earlier schema"
+            })))
+            .expect(expected)
+            .mount(server)
+            .await;
+    }
+
+    let config = SchemaRegistryConfig::new()
+        .url(first.uri())
+        .url(second.uri());
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+    let schema = UnregisteredSchema::schema(r#"{"type":"string"}"#);
+
+    let result = client
+        .post_new_subject_version("book-value", &schema, false)
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn ping_succeeds_while_one_url_is_down() {
+    let dead = MockServer::start().await;
+    let alive = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/config"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&dead)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/config"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "compatibilityLevel": "BACKWARD" })),
+        )
+        .expect(1)
+        .mount(&alive)
+        .await;
+
+    let config = SchemaRegistryConfig::new().url(dead.uri()).url(alive.uri());
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    client.ping().await.unwrap();
+}
+
+#[tokio::test]
+async fn ping_fails_when_every_url_is_down() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/config"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let result = client.ping().await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn broadcast_strategy_calls_every_url() {
+    let servers = [MockServer::start().await, MockServer::start().await];
+
+    for server in servers.iter() {
+        Mock::given(method("GET"))
+            .and(path("/subjects"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!(["book-value"]))
+                    .set_delay(Duration::from_millis(100)),
+            )
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    let config = SchemaRegistryConfig::new()
+        .url(servers[0].uri())
+        .url(servers[1].uri())
+        .endpoint_strategy(EndpointStrategy::Broadcast);
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    let subjects = client.get_subjects(false).await.unwrap();
+
+    assert_eq!(subjects, vec!["book-value"]);
+}
+
+#[tokio::test]
+async fn failover_in_order_strategy_stops_at_the_first_success() {
+    let servers = [
+        MockServer::start().await,
+        MockServer::start().await,
+        MockServer::start().await,
+    ];
+
+    for (server, (status, expected)) in servers.iter().zip([(503, 1), (200, 1), (200, 0)]) {
+        Mock::given(method("GET"))
+            .and(path("/subjects"))
+            .respond_with(ResponseTemplate::new(status).set_body_json(json!(["book-value"])))
+            .expect(expected)
+            .mount(server)
+            .await;
+    }
+
+    let config = servers
+        .iter()
+        .fold(SchemaRegistryConfig::new(), |config, server| {
+            config.url(server.uri())
+        })
+        .endpoint_strategy(EndpointStrategy::FailoverInOrder);
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    let (_, report) = client
+        .with_failover_report(|client| async move { client.get_subjects(false).await })
+        .await
+        .unwrap();
+
+    let outcomes: Vec<_> = report.attempts.iter().map(|a| &a.outcome).collect();
+    assert!(matches!(outcomes[0], AttemptOutcome::Failed { .. }));
+    assert_eq!(outcomes[1], &AttemptOutcome::Succeeded);
+    assert_eq!(outcomes[2], &AttemptOutcome::Skipped);
+    assert!(report.attempts[0].url.starts_with(&servers[0].uri()));
+    assert!(report.attempts[1].url.starts_with(&servers[1].uri()));
+}
+
+#[tokio::test]
+async fn round_robin_strategy_starts_from_the_next_url_on_every_call() {
+    let servers = [
+        MockServer::start().await,
+        MockServer::start().await,
+        MockServer::start().await,
+    ];
+
+    for server in servers.iter() {
+        Mock::given(method("GET"))
+            .and(path("/subjects"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!(["book-value"])))
+            .expect(2)
+            .mount(server)
+            .await;
+    }
+
+    let config = servers
+        .iter()
+        .fold(SchemaRegistryConfig::new(), |config, server| {
+            config.url(server.uri())
+        })
+        .endpoint_strategy(EndpointStrategy::RoundRobin);
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    for index in [0, 1, 2, 0, 1, 2] {
+        let (_, report) = client
+            .with_failover_report(|client| async move { client.get_subjects(false).await })
+            .await
+            .unwrap();
+
+        assert_eq!(report.attempts[0].outcome, AttemptOutcome::Succeeded);
+        assert!(report.attempts[0].url.starts_with(&servers[index].uri()));
+    }
+}
+
+#[tokio::test]
+async fn on_retry_is_invoked_before_each_retry() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(2)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["book-value"])))
+        .mount(&server)
+        .await;
+
+    let attempts = Arc::new(Mutex::new(Vec::new()));
+    let recorded = attempts.clone();
+    let policy = RetryPolicy::new(3)
+        .base_delay(Duration::from_millis(1))
+        .on_retry(Arc::new(move |info: RetryInfo| {
+            recorded.lock().unwrap().push(info.attempt);
+        }));
+
+    let config = SchemaRegistryConfig::new().url(server.uri()).retry(policy);
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    let subjects = client.get_subjects(false).await.unwrap();
+
+    assert_eq!(subjects, vec!["book-value"]);
+    assert_eq!(*attempts.lock().unwrap(), vec![1, 2]);
+}
+
+#[tokio::test]
+async fn connect_timeout_bounds_unreachable_urls() {
+    let config = SchemaRegistryConfig::new()
+        .url("http://10.255.255.1:8081")
+        .connect_timeout(Duration::from_millis(200))
+        .timeout(Duration::from_millis(500));
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    let result = tokio::time::timeout(Duration::from_secs(5), client.get_subjects(false))
+        .await
+        .expect("call should not hang");
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+#[traced_test]
+async fn response_bodies_are_logged_when_enabled() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["logged-value"])))
+        .mount(&server)
+        .await;
+
+    let config = SchemaRegistryConfig::new()
+        .url(server.uri())
+        .log_bodies(true);
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    let subjects = client.get_subjects(false).await.unwrap();
+
+    assert_eq!(subjects, vec!["logged-value"]);
+    assert!(logs_contain("logged-value"));
+}
+
+#[tokio::test]
+#[traced_test]
+async fn response_bodies_are_not_logged_by_default() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["unlogged-value"])))
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    client.get_subjects(false).await.unwrap();
+
+    assert!(!logs_contain("unlogged-value"));
+}
+
+#[tokio::test]
+async fn unavailable_registry_is_retried_until_it_answers() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["book-value"])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let policy = RetryPolicy::new(3)
+        .base_delay(Duration::from_millis(1))
+        .max_delay(Duration::from_millis(5))
+        .jitter(0.5);
+    let config = SchemaRegistryConfig::new().url(server.uri()).retry(policy);
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    let subjects = client.get_subjects(false).await.unwrap();
+
+    assert_eq!(subjects, vec!["book-value"]);
+}
+
+#[tokio::test]
+async fn server_errors_with_a_registry_body_are_retried() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(ResponseTemplate::new(503).set_body_json(json!({
+            "error_code": 503,
+            "message": "Service Unavailable"
+        })))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["book-value"])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let policy = RetryPolicy::new(3).base_delay(Duration::from_millis(1));
+    let config = SchemaRegistryConfig::new().url(server.uri()).retry(policy);
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    let subjects = client.get_subjects(false).await.unwrap();
+
+    assert_eq!(subjects, vec!["book-value"]);
+}
+
+#[tokio::test]
+async fn nan_jitter_retries_without_jitter() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["book-value"])))
+        .mount(&server)
+        .await;
+
+    let delays = Arc::new(Mutex::new(Vec::new()));
+    let recorded = delays.clone();
+    let policy = RetryPolicy::new(2)
+        .base_delay(Duration::from_millis(1))
+        .jitter(f64::NAN)
+        .on_retry(Arc::new(move |info: RetryInfo| {
+            recorded.lock().unwrap().push(info.delay);
+        }));
+    let config = SchemaRegistryConfig::new().url(server.uri()).retry(policy);
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    let subjects = client.get_subjects(false).await.unwrap();
+
+    assert_eq!(subjects, vec!["book-value"]);
+    assert_eq!(*delays.lock().unwrap(), vec![Duration::from_millis(1)]);
+}
+
+#[tokio::test]
+async fn retry_after_is_capped_at_the_max_delay() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(ResponseTemplate::new(503).insert_header("Retry-After", "3600"))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["book-value"])))
+        .mount(&server)
+        .await;
+
+    let policy = RetryPolicy::new(2).max_delay(Duration::from_millis(10));
+    let config = SchemaRegistryConfig::new().url(server.uri()).retry(policy);
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    let subjects = tokio::time::timeout(Duration::from_secs(5), client.get_subjects(false))
+        .await
+        .expect("Retry-After should be capped")
+        .unwrap();
+
+    assert_eq!(subjects, vec!["book-value"]);
+}
+
+#[tokio::test]
+async fn client_errors_are_not_retried() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error_code": 40401,
+            "message": "Subject 'book-value' not found."
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let policy = RetryPolicy::new(3).base_delay(Duration::from_millis(1));
+    let config = SchemaRegistryConfig::new().url(server.uri()).retry(policy);
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    let result = client.get_subject_versions("book-value").await;
+
+    assert!(matches!(
+        result,
+        Err(SchemaRegistryError::SubjectNotFound { .. })
+    ));
+}
+
+#[cfg(all(feature = "uds", unix))]
+#[tokio::test]
+async fn unix_socket_connects_over_the_socket() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::UnixListener;
+
+    let dir = std::env::temp_dir().join(format!("schema-registry-uds-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let socket = dir.join("registry.sock");
+    let _ = std::fs::remove_file(&socket);
+    let listener = UnixListener::bind(&socket).unwrap();
+
+    let server = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut request = vec![0; 4096];
+        let read = stream.read(&mut request).await.unwrap();
+        let body = r#"["book-value"]"#;
+        let response = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        stream.write_all(response.as_bytes()).await.unwrap();
+
+        String::from_utf8_lossy(&request[..read]).into_owned()
+    });
+
+    let config = SchemaRegistryConfig::new()
+        .url("http://localhost")
+        .unix_socket(&socket);
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    let subjects = client.get_subjects(false).await.unwrap();
+    let request = server.await.unwrap();
+
+    assert_eq!(subjects, vec!["book-value"]);
+    assert!(request.starts_with("GET /subjects?deleted=false "));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn pre_built_http_client_is_used_as_is() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .and(header("user-agent", "inventory-service/1.0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["book-value"])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let http = reqwest::Client::builder()
+        .user_agent("inventory-service/1.0")
+        .build()
+        .unwrap();
+    let client = SchemaRegistryClient::from_client(vec![server.uri()], http);
+
+    let subjects = client.get_subjects(false).await.unwrap();
+
+    assert_eq!(subjects, vec!["book-value"]);
+}
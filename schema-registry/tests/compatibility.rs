@@ -0,0 +1,171 @@
+use futures::StreamExt;
+use schema_registry::api::SchemaRegistryAPI;
+use schema_registry::client::SchemaRegistryClient;
+use schema_registry::types::{UnregisteredSchema, Version};
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const SCHEMA: &str = r#"{"type":"record","name":"User","fields":[{"name":"name","type":"string"},{"name":"age","type":"int"}]}"#;
+
+#[tokio::test]
+async fn is_compatible_verbose_reports_messages() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/compatibility/subjects/user-value/versions/latest"))
+        .and(query_param("verbose", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "is_compatible": false,
+            "messages": ["{errorType:'READER_FIELD_MISSING_DEFAULT_VALUE', additionalInfo:'age'}"]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+    let schema = UnregisteredSchema::schema(SCHEMA);
+
+    let result = client
+        .is_compatible_verbose("user-value", Version::Latest, &schema)
+        .await
+        .unwrap();
+
+    assert!(!result.compatible);
+    assert_eq!(result.messages.len(), 1);
+}
+
+#[tokio::test]
+async fn is_compatible_with_versions_checks_only_the_provided_versions() {
+    let server = MockServer::start().await;
+
+    for (version, compatible) in [(1, false), (2, true), (4, true)] {
+        Mock::given(method("POST"))
+            .and(path(format!(
+                "/compatibility/subjects/user-value/versions/{}",
+                version
+            )))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "is_compatible": compatible })),
+            )
+            .expect(if version == 1 { 0 } else { 1 })
+            .mount(&server)
+            .await;
+    }
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+    let schema = UnregisteredSchema::schema(SCHEMA);
+
+    let compatible = client
+        .is_compatible_with_versions("user-value", &[2, 4], &schema)
+        .await
+        .unwrap();
+
+    assert!(compatible);
+}
+
+#[tokio::test]
+async fn check_stream_yields_results_by_index() {
+    let server = MockServer::start().await;
+
+    let schemas = [
+        r#"{"type":"record","name":"User","fields":[{"name":"name","type":"string"}]}"#,
+        r#"{"type":"record","name":"User","fields":[{"name":"age","type":"int"}]}"#,
+        SCHEMA,
+    ];
+
+    for (schema, compatible) in schemas.iter().zip([true, false, true]) {
+        Mock::given(method("POST"))
+            .and(path("/compatibility/subjects/user-value/versions/latest"))
+            .and(body_partial_json(json!({ "schema": schema })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "is_compatible": compatible })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let mut results = client
+        .check_stream(
+            "user-value",
+            schemas
+                .iter()
+                .map(|s| UnregisteredSchema::schema(*s))
+                .collect(),
+        )
+        .map(|(index, result)| (index, result.unwrap()))
+        .collect::<Vec<_>>()
+        .await;
+    results.sort();
+
+    assert_eq!(results, vec![(0, true), (1, false), (2, true)]);
+}
+
+#[tokio::test]
+async fn validate_version_chain_checks_adjacent_versions() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/user-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([1, 2, 3])))
+        .mount(&server)
+        .await;
+
+    for version in [2, 3] {
+        Mock::given(method("GET"))
+            .and(path(format!("/subjects/user-value/versions/{}", version)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": 10 + version,
+                "subject": "user-value",
+                "version": version,
+                "schema": SCHEMA
+            })))
+            .mount(&server)
+            .await;
+    }
+
+    for (version, compatible) in [(1, true), (2, false)] {
+        Mock::given(method("POST"))
+            .and(path(format!(
+                "/compatibility/subjects/user-value/versions/{}",
+                version
+            )))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "is_compatible": compatible })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let chain = client.validate_version_chain("user-value").await.unwrap();
+
+    assert_eq!(chain, vec![(1, 2, true), (2, 3, false)]);
+}
+
+#[tokio::test]
+async fn is_fully_compatible_is_callable_through_a_trait_object() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/compatibility/subjects/user-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "is_compatible": true })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+    let api: &dyn SchemaRegistryAPI = &client;
+
+    let compatible = api
+        .is_fully_compatible("user-value", &UnregisteredSchema::schema(SCHEMA))
+        .await
+        .unwrap();
+
+    assert!(compatible);
+}
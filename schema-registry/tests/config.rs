@@ -0,0 +1,323 @@
+use std::collections::HashMap;
+
+use schema_registry::api::SchemaRegistryAPI;
+use schema_registry::client::config::SchemaRegistryConfig;
+use schema_registry::client::SchemaRegistryClient;
+use schema_registry::types::{CompatibilityLevel, ContractPolicy, SubjectConfig};
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn resolve_contract_policy_includes_compatibility_group() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/config/orders-value"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "compatibilityLevel": "BACKWARD",
+            "compatibilityGroup": "application.major.version",
+            "defaultMetadata": { "application.major.version": "1", "owner": "orders" },
+            "overrideMetadata": { "application.major.version": "2" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let policy = client
+        .resolve_contract_policy("orders-value")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        policy,
+        ContractPolicy {
+            compatibility_level: Some(CompatibilityLevel::Backward),
+            compatibility_group: Some("application.major.version".to_owned()),
+            metadata: HashMap::from([
+                ("application.major.version".to_owned(), "2".to_owned()),
+                ("owner".to_owned(), "orders".to_owned()),
+            ]),
+        }
+    );
+}
+
+#[tokio::test]
+async fn raw_configuration_preserves_unknown_fields() {
+    let server = MockServer::start().await;
+
+    let config = json!({
+        "compatibilityLevel": "FULL",
+        "normalize": true,
+        "validateFields": true
+    });
+
+    Mock::given(method("GET"))
+        .and(path("/config"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(config.clone()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/config/orders-value"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(config.clone()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let global = client.get_configuration_raw().await.unwrap();
+    let subject = client
+        .get_subject_configuration_raw("orders-value")
+        .await
+        .unwrap();
+
+    assert_eq!(global, config);
+    assert_eq!(subject["validateFields"], true);
+}
+
+#[tokio::test]
+async fn compatibilities_are_restored_from_a_snapshot() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            "author-value",
+            "book-value",
+            "library-value",
+            "magazine-value"
+        ])))
+        .mount(&server)
+        .await;
+
+    for (subject, level) in [("author-value", "BACKWARD"), ("book-value", "FULL")] {
+        Mock::given(method("GET"))
+            .and(path(format!("/config/{}", subject)))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "compatibilityLevel": level })),
+            )
+            .mount(&server)
+            .await;
+
+        Mock::given(method("PUT"))
+            .and(path(format!("/config/{}", subject)))
+            .and(body_json(json!({ "compatibility": level })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "compatibility": level })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("PUT"))
+            .and(path(format!("/config/{}", subject)))
+            .and(body_json(json!({ "compatibility": "NONE" })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "compatibility": "NONE" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    Mock::given(method("GET"))
+        .and(path("/config/library-value"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error_code": 40408,
+            "message": "Subject 'library-value' does not have subject-level compatibility configured"
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/config/library-value"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "compatibility": "NONE" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/config/library-value"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "compatibility": "NONE" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/config/magazine-value"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "defaultMetadata": { "owner": "magazines" }
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/config/magazine-value"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let snapshot = client.snapshot_compatibilities().await.unwrap();
+
+    assert_eq!(
+        snapshot,
+        HashMap::from([
+            (
+                "author-value".to_owned(),
+                Some(CompatibilityLevel::Backward)
+            ),
+            ("book-value".to_owned(), Some(CompatibilityLevel::Full)),
+            ("library-value".to_owned(), None),
+            ("magazine-value".to_owned(), None),
+        ])
+    );
+
+    for subject in ["author-value", "book-value", "library-value"] {
+        let none = SubjectConfig::new().compatibility_level(CompatibilityLevel::None);
+        client
+            .update_subject_configuration(subject, &none)
+            .await
+            .unwrap();
+    }
+
+    client.restore_compatibilities(&snapshot).await.unwrap();
+}
+
+#[tokio::test]
+async fn patch_subject_configuration_keeps_unset_fields() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/config/orders-value"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "compatibilityLevel": "BACKWARD",
+            "defaultMetadata": { "owner": "orders" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/config/orders-value"))
+        .and(body_json(json!({
+            "compatibility": "FULL",
+            "defaultMetadata": { "owner": "orders" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "compatibility": "FULL",
+            "defaultMetadata": { "owner": "orders" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+    let patch = SubjectConfig::new().compatibility_level(CompatibilityLevel::Full);
+
+    let merged = client
+        .patch_subject_configuration("orders-value", patch)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        merged,
+        SubjectConfig::new()
+            .compatibility_level(CompatibilityLevel::Full)
+            .default_metadata(HashMap::from([("owner".to_owned(), "orders".to_owned())]))
+    );
+}
+
+#[tokio::test]
+async fn delete_subject_configuration_returns_the_deleted_config() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/config/orders-value"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "compatibilityLevel": "FULL" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let deleted = client
+        .delete_subject_configuration("orders-value")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        deleted,
+        SubjectConfig::new().compatibility_level(CompatibilityLevel::Full)
+    );
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+#[test]
+fn root_certificate_is_loaded_from_pem() {
+    let config = SchemaRegistryConfig::new()
+        .url("https://localhost:8081")
+        .add_root_certificate_pem(include_bytes!("certs/ca.pem"))
+        .unwrap();
+
+    assert!(SchemaRegistryClient::from_conf(config).is_ok());
+}
+
+#[cfg(feature = "native-tls")]
+#[test]
+fn invalid_root_certificate_is_rejected() {
+    use schema_registry::error::ConfigurationError;
+
+    let result = SchemaRegistryConfig::new().add_root_certificate_pem(b"not a certificate");
+
+    assert!(matches!(
+        result,
+        Err(ConfigurationError::Certificate { .. })
+    ));
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+#[test]
+fn invalid_certificates_can_be_accepted() {
+    let config = SchemaRegistryConfig::new()
+        .url("https://localhost:8081")
+        .danger_accept_invalid_certs(true);
+
+    assert!(SchemaRegistryClient::from_conf(config).is_ok());
+}
+
+#[tokio::test]
+async fn subject_configuration_defaults_to_global_when_requested() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/config/book-value"))
+        .and(query_param("defaultToGlobal", "true"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "compatibilityLevel": "FULL" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let config = client
+        .get_subject_configuration_with_default("book-value", true)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        config,
+        SubjectConfig::new().compatibility_level(CompatibilityLevel::Full)
+    );
+}
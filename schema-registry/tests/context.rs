@@ -0,0 +1,107 @@
+use schema_registry::api::SchemaRegistryAPI;
+use schema_registry::client::SchemaRegistryClient;
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn get_subjects_in_context_returns_unqualified_names() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .and(query_param("subjectPrefix", ":.orders:"))
+        .and(query_param("deleted", "false"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!([":.orders:order-key", ":.orders:order-value"])),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let subjects = client
+        .get_subjects_in_context(".orders", false)
+        .await
+        .unwrap();
+
+    assert_eq!(subjects, vec!["order-key", "order-value"]);
+}
+
+#[tokio::test]
+async fn get_subjects_in_context_encodes_the_context() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .and(query_param("subjectPrefix", ":.a b&c#d:"))
+        .and(query_param("deleted", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([":.a b&c#d:order-value"])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let subjects = client
+        .get_subjects_in_context("a b&c#d", true)
+        .await
+        .unwrap();
+
+    assert_eq!(subjects, vec!["order-value"]);
+}
+
+#[tokio::test]
+async fn get_subjects_filtered_sends_no_prefix_without_filters() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .and(query_param_is_missing("subjectPrefix"))
+        .and(query_param("deleted", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["book-value"])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let subjects = client
+        .get_subjects_filtered(None, None, true)
+        .await
+        .unwrap();
+
+    assert_eq!(subjects, vec!["book-value"]);
+}
+
+#[tokio::test]
+async fn get_subjects_filtered_builds_the_subject_prefix() {
+    let cases = [
+        (Some("book"), None, "book"),
+        (Some("a b&c"), None, "a b&c"),
+        (None, Some(".orders"), ":.orders:"),
+        (Some("order-"), Some("orders"), ":.orders:order-"),
+    ];
+
+    for (prefix, context, expected) in cases {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/subjects"))
+            .and(query_param("subjectPrefix", expected))
+            .and(query_param("deleted", "false"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+        client
+            .get_subjects_filtered(prefix, context, false)
+            .await
+            .unwrap();
+    }
+}
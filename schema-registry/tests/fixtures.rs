@@ -0,0 +1,25 @@
+#![cfg(feature = "test-util")]
+
+use schema_registry::api::{MockSchemaRegistryAPI, SchemaRegistryAPI};
+use schema_registry::test_fixtures::{sample_avro_subject, sample_exporter_status, AVRO_AUTHOR};
+use schema_registry::types::{ExporterState, SchemaType, Version};
+
+#[tokio::test]
+async fn fixtures_can_be_returned_from_mocks() {
+    let mut sr = MockSchemaRegistryAPI::new();
+    sr.expect_get_subject_version()
+        .returning(|_, _| Ok(sample_avro_subject()));
+    sr.expect_get_exporter_status()
+        .returning(|_| Ok(sample_exporter_status()));
+
+    let subject = sr
+        .get_subject_version("author-value", Version::Latest)
+        .await
+        .unwrap();
+    let status = sr.get_exporter_status("exporter").await.unwrap();
+
+    assert_eq!(subject.subject, "author-value");
+    assert_eq!(subject.schema_type, SchemaType::Avro);
+    assert_eq!(subject.schema, AVRO_AUTHOR);
+    assert_eq!(status.state, ExporterState::Running);
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use schema_registry::api::SchemaRegistryAPI;
+use schema_registry::client::SchemaRegistryClient;
+use schema_registry::types::Mode;
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn all_subject_modes_falls_back_to_global_mode() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!(["book-value", "author-value"])),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/mode"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "mode": "READWRITE" })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/mode/book-value"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "mode": "READONLY" })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/mode/author-value"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error_code": 40401,
+            "message": "Subject 'author-value' not found."
+        })))
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let modes = client.all_subject_modes().await.unwrap();
+
+    assert_eq!(
+        modes,
+        HashMap::from([
+            ("book-value".to_owned(), Mode::ReadOnly),
+            ("author-value".to_owned(), Mode::ReadWrite),
+        ])
+    );
+}
+
+#[tokio::test]
+async fn subject_mode_defaults_to_global_mode_when_requested() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/mode/book-value"))
+        .and(query_param("defaultToGlobal", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "mode": "READONLY" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let mode = client
+        .get_subject_resource_mode_with_default("book-value", true)
+        .await
+        .unwrap();
+
+    assert_eq!(mode, Mode::ReadOnly);
+}
+
+#[tokio::test]
+async fn delete_subject_mode_returns_the_cleared_mode() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/mode/book-value"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "mode": "READONLY" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let mode = client.delete_subject_mode("book-value").await.unwrap();
+
+    assert_eq!(mode, Some(Mode::ReadOnly));
+}
+
+#[tokio::test]
+async fn delete_subject_mode_without_mode_returns_none() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/mode/book-value"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error_code": 40401,
+            "message": "Subject 'book-value' not found."
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let mode = client.delete_subject_mode("book-value").await.unwrap();
+
+    assert_eq!(mode, None);
+}
@@ -0,0 +1,494 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use futures::AsyncReadExt;
+use schema_registry::api::SchemaRegistryAPI;
+use schema_registry::client::cache::CachedSchemaRegistryClient;
+use schema_registry::client::config::SchemaRegistryConfig;
+use schema_registry::client::SchemaRegistryClient;
+use schema_registry::error::SchemaRegistryError;
+use schema_registry::types::{SubjectVersion, UnregisteredSchema, Version};
+use serde_json::{json, Value};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn delete_schema_everywhere_removes_every_subject_version() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/schemas/ids/7/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "subject": "book-value", "version": 2 },
+            { "subject": "library-value", "version": 1 }
+        ])))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([1, 2])))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/library-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([1, 2, 3])))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/subjects/book-value/versions/2"))
+        .and(query_param("permanent", "false"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(2)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/subjects/library-value/versions/1"))
+        .and(query_param("permanent", "false"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(1)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let deleted = client
+        .delete_schema_everywhere(7, false, false)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        deleted,
+        vec![
+            SubjectVersion {
+                subject: "book-value".to_owned(),
+                version: 2,
+            },
+            SubjectVersion {
+                subject: "library-value".to_owned(),
+                version: 1,
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn delete_schema_everywhere_refuses_to_delete_last_version() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/schemas/ids/7/versions"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!([{ "subject": "book-value", "version": 1 }])),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([1])))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(1)))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let result = client.delete_schema_everywhere(7, false, false).await;
+
+    assert!(matches!(
+        result,
+        Err(SchemaRegistryError::LastSubjectVersion { .. })
+    ));
+}
+
+#[tokio::test]
+async fn delete_schema_everywhere_checks_every_subject_before_deleting() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/schemas/ids/7/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "subject": "book-value", "version": 2 },
+            { "subject": "library-value", "version": 1 },
+            { "subject": "library-value", "version": 2 }
+        ])))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([1, 2])))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/library-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([1, 2])))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(1)))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let result = client.delete_schema_everywhere(7, false, false).await;
+
+    assert!(matches!(
+        result,
+        Err(SchemaRegistryError::LastSubjectVersion { ref subject, version: 2 })
+            if subject == "library-value"
+    ));
+}
+
+#[tokio::test]
+async fn normalize_compare_returns_original_and_normalized_forms() {
+    let server = MockServer::start().await;
+
+    let original = r#"{"name":"Book","type":"record","fields":[{"type":"int","name":"id"}]}"#;
+    let normalized = r#"{"type":"record","name":"Book","fields":[{"name":"id","type":"int"}]}"#;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/book-value"))
+        .and(query_param("normalize", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 1,
+            "subject": "book-value",
+            "version": 1,
+            "schema": normalized
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+    let schema = UnregisteredSchema::schema(original);
+
+    let (before, after) = client
+        .normalize_compare("book-value", &schema)
+        .await
+        .unwrap();
+
+    assert_eq!(before, original);
+    assert_eq!(after, normalized);
+    assert_ne!(before, after);
+}
+
+#[tokio::test]
+async fn schema_stream_reconstructs_the_response_body() {
+    let server = MockServer::start().await;
+
+    let fields = (0..500)
+        .map(|i| format!(r#"{{"name":"field_{}","type":"string"}}"#, i))
+        .collect::<Vec<_>>()
+        .join(",");
+    let schema = format!(r#"{{"type":"record","name":"Wide","fields":[{}]}}"#, fields);
+    let body = json!({ "schema": schema }).to_string();
+
+    Mock::given(method("GET"))
+        .and(path("/schemas/ids/3"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(body.clone()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let mut reader = client.get_schema_stream_by_id(3).await.unwrap();
+
+    let mut received = Vec::new();
+    let mut chunk = [0u8; 1024];
+
+    loop {
+        let read = reader.read(&mut chunk).await.unwrap();
+
+        if read == 0 {
+            break;
+        }
+
+        received.extend_from_slice(&chunk[..read]);
+    }
+
+    assert_eq!(String::from_utf8(received).unwrap(), body);
+}
+
+async fn mount_subjects_for_lookup(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!(["author-value", "book-value"])),
+        )
+        .mount(server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/author-value"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error_code": 40403,
+            "message": "Schema not found"
+        })))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn find_id_by_schema_returns_id_of_registered_schema() {
+    let server = MockServer::start().await;
+    mount_subjects_for_lookup(&server).await;
+
+    let book = r#"{"type":"record","name":"Book","fields":[{"name":"id","type":"int"}]}"#;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/book-value"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 12,
+            "subject": "book-value",
+            "version": 3,
+            "schema": book
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let id = client
+        .find_id_by_schema(&UnregisteredSchema::schema(book))
+        .await
+        .unwrap();
+
+    assert_eq!(id, Some(12));
+}
+
+#[tokio::test]
+async fn find_id_by_schema_returns_none_for_unknown_schema() {
+    let server = MockServer::start().await;
+    mount_subjects_for_lookup(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/book-value"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error_code": 40403,
+            "message": "Schema not found"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let id = client
+        .find_id_by_schema(&UnregisteredSchema::schema(r#"{"type":"string"}"#))
+        .await
+        .unwrap();
+
+    assert_eq!(id, None);
+}
+
+#[tokio::test]
+async fn cache_miss_callback_fires_once_per_schema_id() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/schemas/ids/5"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "schema": "\"string\""
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/schemas/ids/5/schema"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("\"string\""))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let misses = Arc::new(AtomicUsize::new(0));
+    let counter = misses.clone();
+
+    let config = SchemaRegistryConfig::new()
+        .url(server.uri())
+        .on_cache_miss(Arc::new(move |id| {
+            assert_eq!(id, 5);
+            counter.fetch_add(1, Ordering::SeqCst);
+        }));
+    let client = CachedSchemaRegistryClient::from_conf(config).unwrap();
+
+    let miss = client.get_schema_by_id(5).await.unwrap();
+    let hit = client.get_schema_by_id(5).await.unwrap();
+
+    assert_eq!(miss, hit);
+    assert_eq!(misses.load(Ordering::SeqCst), 1);
+
+    let raw_miss = client.get_schema_by_id_raw(5).await.unwrap();
+    let raw_hit = client.get_schema_by_id_raw(5).await.unwrap();
+
+    assert_eq!(raw_miss.0, raw_hit.0);
+    assert_eq!(misses.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn raw_schema_endpoints_return_the_body_untouched() {
+    let server = MockServer::start().await;
+    let schema = r#"{"type":"record","name":"Book","fields":[{"name":"id","type":"int"}]}"#;
+
+    Mock::given(method("GET"))
+        .and(path("/schemas/ids/5/schema"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(schema))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions/1/schema"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(schema))
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let by_id = client.get_schema_by_id_raw(5).await.unwrap();
+    let by_version = client
+        .get_subject_version_raw("book-value", Version::Number(1))
+        .await
+        .unwrap();
+
+    assert_eq!(by_id.0, schema);
+    assert_eq!(by_version.0, schema);
+}
+
+#[tokio::test]
+async fn cached_client_fetches_each_schema_once() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/schemas/ids/5"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "schema": "\"string\"" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/schemas/ids/5/schema"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("\"string\""))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let inner = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+    let client = CachedSchemaRegistryClient::with_capacity(inner, 10);
+
+    let first = client.get_schema_by_id(5).await.unwrap();
+    let second = client.get_schema_by_id(5).await.unwrap();
+    assert_eq!(first, second);
+
+    client.get_schema_by_id_raw(5).await.unwrap();
+    client.get_schema_by_id_raw(5).await.unwrap();
+
+    client.clear();
+    client.get_schema_by_id_raw(5).await.unwrap();
+}
+
+async fn mount_subject_version(server: &MockServer, subject: &str, references: Value) {
+    Mock::given(method("GET"))
+        .and(path(format!("/subjects/{}/versions/1", subject)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": subject.len(),
+            "subject": subject,
+            "version": 1,
+            "schema": "{}",
+            "references": references
+        })))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn schema_references_are_resolved_transitively() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/schemas/ids/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "schema": "{}",
+            "references": [{ "name": "author.json", "subject": "author-value", "version": 1 }]
+        })))
+        .mount(&server)
+        .await;
+
+    mount_subject_version(
+        &server,
+        "author-value",
+        json!([{ "name": "address.json", "subject": "address-value", "version": 1 }]),
+    )
+    .await;
+    mount_subject_version(&server, "address-value", json!(null)).await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let resolved = client.get_schema_with_references(1).await.unwrap();
+
+    let dependencies: Vec<_> = resolved
+        .dependencies
+        .iter()
+        .map(|(reference, _)| (reference.name.as_str(), reference.subject.as_str()))
+        .collect();
+
+    assert_eq!(resolved.root.references.unwrap().len(), 1);
+    assert_eq!(
+        dependencies,
+        vec![
+            ("address.json", "address-value"),
+            ("author.json", "author-value")
+        ]
+    );
+}
+
+#[tokio::test]
+async fn schema_reference_cycle_is_an_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/schemas/ids/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "schema": "{}",
+            "references": [{ "name": "a.json", "subject": "a-value", "version": 1 }]
+        })))
+        .mount(&server)
+        .await;
+
+    mount_subject_version(
+        &server,
+        "a-value",
+        json!([{ "name": "b.json", "subject": "b-value", "version": 1 }]),
+    )
+    .await;
+    mount_subject_version(
+        &server,
+        "b-value",
+        json!([{ "name": "a.json", "subject": "a-value", "version": 1 }]),
+    )
+    .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let result = client.get_schema_with_references(1).await;
+
+    assert!(matches!(
+        result,
+        Err(SchemaRegistryError::ReferenceCycle { .. })
+    ));
+}
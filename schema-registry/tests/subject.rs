@@ -0,0 +1,1221 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use futures::StreamExt;
+use schema_registry::api::SchemaRegistryAPI;
+use schema_registry::client::config::SchemaRegistryConfig;
+use schema_registry::client::SchemaRegistryClient;
+use schema_registry::error::SchemaRegistryError;
+use schema_registry::types::{RegistrationOutcome, SubjectVersion, UnregisteredSchema, Version};
+use serde_json::{json, Value};
+use wiremock::matchers::{body_json, method, path, query_param, query_param_is_missing};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const SCHEMA: &str = r#"{"type":"record","name":"Book","fields":[{"name":"id","type":"int"}]}"#;
+
+#[tokio::test]
+async fn register_and_await_readable_polls_until_schema_is_visible() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/book-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 5 })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/schemas/ids/5"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error_code": 40403,
+            "message": "Schema 5 not found"
+        })))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/schemas/ids/5"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "schema": SCHEMA })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+    let schema = UnregisteredSchema::schema(SCHEMA);
+
+    let id = client
+        .register_and_await_readable("book-value", &schema, false, Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    assert_eq!(id, 5);
+}
+
+#[tokio::test]
+async fn register_and_await_readable_returns_errors_other_than_not_found() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/book-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 5 })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/schemas/ids/5"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(json!({
+            "error_code": 40301,
+            "message": "User is denied operation Read on schema 5"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+    let schema = UnregisteredSchema::schema(SCHEMA);
+
+    let error = client
+        .register_and_await_readable("book-value", &schema, false, Duration::from_secs(5))
+        .await
+        .unwrap_err();
+
+    assert!(!matches!(error, SchemaRegistryError::Timeout { .. }));
+}
+
+#[tokio::test]
+async fn register_topic_schemas_registers_key_and_value_subjects() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/books-key/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 1 })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/books-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 2 })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+    let key_schema = UnregisteredSchema::schema(r#""string""#);
+    let value_schema = UnregisteredSchema::schema(SCHEMA);
+
+    let ids = client
+        .register_topic_schemas("books", &key_schema, &value_schema, false)
+        .await
+        .unwrap();
+
+    assert_eq!(ids, (1, 2));
+}
+
+#[tokio::test]
+async fn register_many_reports_each_subject_in_input_order() {
+    let server = MockServer::start().await;
+
+    for (subject, id) in [("authors-value", 1), ("books-value", 2)] {
+        Mock::given(method("POST"))
+            .and(path(format!("/subjects/{}/versions", subject)))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "id": id }))
+                    .set_delay(Duration::from_millis(50 * id)),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/orders-value/versions"))
+        .respond_with(ResponseTemplate::new(422).set_body_json(json!({
+            "error_code": 42201,
+            "message": "Invalid schema"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+    let items = ["books-value", "orders-value", "authors-value"]
+        .into_iter()
+        .map(|subject| (subject.to_owned(), UnregisteredSchema::schema(SCHEMA)))
+        .collect();
+
+    let results = client.register_many(items, 2).await;
+
+    let subjects: Vec<_> = results
+        .iter()
+        .map(|(subject, _)| subject.as_str())
+        .collect();
+    assert_eq!(subjects, ["books-value", "orders-value", "authors-value"]);
+    assert_eq!(results[0].1.as_ref().unwrap(), &2);
+    assert!(matches!(
+        results[1].1,
+        Err(SchemaRegistryError::InvalidSchema { .. })
+    ));
+    assert_eq!(results[2].1.as_ref().unwrap(), &1);
+}
+
+#[tokio::test]
+async fn deletion_impact_walks_references_transitively() {
+    let server = MockServer::start().await;
+
+    let references = [
+        ("author-value", json!([2])),
+        ("book-value", json!([3])),
+        ("library-value", json!([])),
+    ];
+
+    for (subject, ids) in references {
+        Mock::given(method("GET"))
+            .and(path(format!(
+                "/subjects/{}/versions/1/referencedBy",
+                subject
+            )))
+            .respond_with(ResponseTemplate::new(200).set_body_json(ids))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let referrers = [(2, "book-value"), (3, "library-value")];
+
+    for (id, subject) in referrers {
+        Mock::given(method("GET"))
+            .and(path(format!("/schemas/ids/{}/versions", id)))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!([{ "subject": subject, "version": 1 }])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let impacted = client
+        .deletion_impact("author-value", Version::Number(1))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        impacted,
+        vec![
+            SubjectVersion {
+                subject: "book-value".to_owned(),
+                version: 1,
+            },
+            SubjectVersion {
+                subject: "library-value".to_owned(),
+                version: 1,
+            },
+        ]
+    );
+}
+
+async fn mount_registration(server: &MockServer, versions_before: ResponseTemplate, version: u32) {
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions"))
+        .respond_with(versions_before)
+        .expect(1)
+        .mount(server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/book-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 10 })))
+        .expect(1)
+        .mount(server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/book-value"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 10,
+            "subject": "book-value",
+            "version": version,
+            "schema": SCHEMA
+        })))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn register_subject_version_detailed_reports_new_version() {
+    let server = MockServer::start().await;
+    let not_found = ResponseTemplate::new(404).set_body_json(json!({
+        "error_code": 40401,
+        "message": "Subject 'book-value' not found."
+    }));
+    mount_registration(&server, not_found, 1).await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+    let schema = UnregisteredSchema::schema(SCHEMA);
+
+    let outcome = client
+        .register_subject_version_detailed("book-value", &schema, false)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        outcome,
+        RegistrationOutcome {
+            id: 10,
+            version: 1,
+            created: true,
+        }
+    );
+}
+
+#[tokio::test]
+async fn register_subject_version_detailed_reports_existing_version() {
+    let server = MockServer::start().await;
+    let versions = ResponseTemplate::new(200).set_body_json(json!([1, 2]));
+    mount_registration(&server, versions, 2).await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+    let schema = UnregisteredSchema::schema(SCHEMA);
+
+    let outcome = client
+        .register_subject_version_detailed("book-value", &schema, false)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        outcome,
+        RegistrationOutcome {
+            id: 10,
+            version: 2,
+            created: false,
+        }
+    );
+}
+
+/// Mount a lookup of `book-value` answering `lookup`, and a registration expected `registrations`
+/// times.
+async fn mount_lookup_then_register(
+    server: &MockServer,
+    lookup: ResponseTemplate,
+    registrations: u64,
+) {
+    Mock::given(method("POST"))
+        .and(path("/subjects/book-value"))
+        .respond_with(lookup)
+        .expect(1)
+        .mount(server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/book-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 11 })))
+        .expect(registrations)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn register_if_absent_returns_the_existing_id() {
+    let server = MockServer::start().await;
+    let registered = ResponseTemplate::new(200).set_body_json(json!({
+        "id": 10,
+        "subject": "book-value",
+        "version": 1,
+        "schema": SCHEMA
+    }));
+    mount_lookup_then_register(&server, registered, 0).await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+    let schema = UnregisteredSchema::schema(SCHEMA);
+
+    let registered = client
+        .register_if_absent("book-value", &schema, false)
+        .await
+        .unwrap();
+
+    assert_eq!(registered, (10, false));
+}
+
+#[tokio::test]
+async fn register_if_absent_registers_a_missing_schema() {
+    let server = MockServer::start().await;
+    let not_found = ResponseTemplate::new(404).set_body_json(json!({
+        "error_code": 40403,
+        "message": "Schema not found"
+    }));
+    mount_lookup_then_register(&server, not_found, 1).await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+    let schema = UnregisteredSchema::schema(SCHEMA);
+
+    let registered = client
+        .register_if_absent("book-value", &schema, false)
+        .await
+        .unwrap();
+
+    assert_eq!(registered, (11, true));
+}
+
+#[tokio::test]
+async fn register_if_absent_surfaces_other_lookup_errors() {
+    let server = MockServer::start().await;
+    let unauthorized = ResponseTemplate::new(401).set_body_json(json!({
+        "error_code": 40101,
+        "message": "Unauthorized"
+    }));
+    mount_lookup_then_register(&server, unauthorized, 0).await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+    let schema = UnregisteredSchema::schema(SCHEMA);
+
+    let result = client
+        .register_if_absent("book-value", &schema, false)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(SchemaRegistryError::Registry {
+            error_code: 40101,
+            ..
+        })
+    ));
+}
+
+#[tokio::test]
+async fn lookup_respecting_config_normalizes_when_subject_does() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/config/book-value"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "compatibilityLevel": "BACKWARD",
+            "normalize": true
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/book-value"))
+        .and(query_param("normalize", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 10,
+            "subject": "book-value",
+            "version": 1,
+            "schema": SCHEMA
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/book-value"))
+        .and(query_param("normalize", "false"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error_code": 40403,
+            "message": "Schema not found"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+    let schema = UnregisteredSchema::schema(SCHEMA);
+
+    let plain_lookup = client
+        .lookup_subject_schema("book-value", &schema, false)
+        .await;
+    let subject = client
+        .lookup_respecting_config("book-value", &schema)
+        .await
+        .unwrap();
+
+    assert!(plain_lookup.is_err());
+    assert_eq!(subject.id, 10);
+}
+
+#[tokio::test]
+async fn permanent_delete_fails_unless_allowed() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/subjects/book-value"))
+        .and(query_param("permanent", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([1])))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let result = client.delete_subject("book-value", true).await;
+
+    assert!(matches!(
+        result,
+        Err(SchemaRegistryError::PermanentDeleteDisabled { .. })
+    ));
+}
+
+#[tokio::test]
+async fn permanent_delete_proceeds_when_allowed() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/subjects/book-value"))
+        .and(query_param("permanent", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([1])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let config = SchemaRegistryConfig::new()
+        .url(server.uri())
+        .allow_permanent_delete(true);
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    let versions = client.delete_subject("book-value", true).await.unwrap();
+
+    assert_eq!(versions, vec![1]);
+}
+
+#[tokio::test]
+async fn wait_for_versions_returns_once_count_is_reached() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([1])))
+        .up_to_n_times(2)
+        .with_priority(1)
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([1, 2])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let versions = client
+        .wait_for_versions("book-value", 2, Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    assert_eq!(versions, vec![1, 2]);
+}
+
+#[tokio::test]
+async fn changes_since_reports_only_subjects_that_advanced() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!(["author-value", "book-value"])),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/author-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([1, 2])))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([1, 2, 3])))
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let watermark = HashMap::from([("author-value".to_owned(), 2), ("book-value".to_owned(), 1)]);
+
+    let changes = client.changes_since(watermark).await.unwrap();
+
+    assert_eq!(
+        changes,
+        vec![
+            SubjectVersion {
+                subject: "book-value".to_owned(),
+                version: 2,
+            },
+            SubjectVersion {
+                subject: "book-value".to_owned(),
+                version: 3,
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn referenced_subject_delete_requires_force() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/subjects/author-value"))
+        .and(query_param("force", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([1])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/subjects/author-value"))
+        .and(query_param("force", "false"))
+        .respond_with(ResponseTemplate::new(422).set_body_json(json!({
+            "error_code": 42206,
+            "message": "One or more references exist to the schema {magic=1,keytype=SCHEMA,subject=author-value,version=1}"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let refused = client
+        .delete_subject_forced("author-value", false, false)
+        .await;
+    let forced = client
+        .delete_subject_forced("author-value", false, true)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        refused,
+        Err(SchemaRegistryError::ReferenceExists { .. })
+    ));
+    assert_eq!(forced, vec![1]);
+}
+
+#[tokio::test]
+async fn resolve_version_resolves_latest_to_current_version() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions/latest"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 4,
+            "subject": "book-value",
+            "version": 3,
+            "schema": SCHEMA
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let latest = client
+        .resolve_version("book-value", Version::Latest)
+        .await
+        .unwrap();
+    let numbered = client
+        .resolve_version("book-value", Version::Number(2))
+        .await
+        .unwrap();
+
+    assert_eq!(latest, 3);
+    assert_eq!(numbered, 2);
+}
+
+#[tokio::test]
+async fn duplicate_registration_reports_a_stable_version() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error_code": 40401,
+            "message": "Subject 'book-value' not found."
+        })))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([1])))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/book-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 10 })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/book-value"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 10,
+            "subject": "book-value",
+            "version": 1,
+            "schema": SCHEMA
+        })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+    let schema = UnregisteredSchema::schema(SCHEMA);
+
+    let first = client
+        .register_subject_version_detailed("book-value", &schema, false)
+        .await
+        .unwrap();
+    let second = client
+        .register_subject_version_detailed("book-value", &schema, false)
+        .await
+        .unwrap();
+
+    assert_eq!((first.id, first.version, first.created), (10, 1, true));
+    assert_eq!((second.id, second.version, second.created), (10, 1, false));
+}
+
+#[tokio::test]
+async fn numbered_subject_version_is_served_from_cache() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions/2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 4,
+            "subject": "book-value",
+            "version": 2,
+            "schema": SCHEMA
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let fetched = client
+        .get_subject_version_cached("book-value", Version::Number(2))
+        .await
+        .unwrap();
+    let cached = client
+        .get_subject_version_cached("book-value", Version::Number(2))
+        .await
+        .unwrap();
+
+    assert_eq!((fetched.id, fetched.version), (4, 2));
+    assert_eq!((cached.id, cached.version), (4, 2));
+}
+
+#[tokio::test]
+async fn subject_version_cache_evicts_beyond_its_capacity() {
+    let server = MockServer::start().await;
+
+    for version in [1, 2] {
+        Mock::given(method("GET"))
+            .and(path(format!("/subjects/book-value/versions/{}", version)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": 3 + version,
+                "subject": "book-value",
+                "version": version,
+                "schema": SCHEMA
+            })))
+            .expect(if version == 1 { 2 } else { 1 })
+            .mount(&server)
+            .await;
+    }
+
+    let config = SchemaRegistryConfig::new()
+        .url(server.uri())
+        .subject_version_cache_capacity(1);
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    for version in [1, 2, 1] {
+        client
+            .get_subject_version_cached("book-value", Version::Number(version))
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn latest_subject_version_expires_after_ttl() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions/latest"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 4,
+            "subject": "book-value",
+            "version": 2,
+            "schema": SCHEMA
+        })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let config = SchemaRegistryConfig::new()
+        .url(server.uri())
+        .latest_version_ttl(Duration::ZERO);
+    let client = SchemaRegistryClient::from_conf(config).unwrap();
+
+    for _ in 0..2 {
+        client
+            .get_subject_version_cached("book-value", Version::Latest)
+            .await
+            .unwrap();
+    }
+}
+
+async fn mount_subject_version(
+    server: &MockServer,
+    subject: &str,
+    version: &str,
+    references: Value,
+) {
+    Mock::given(method("GET"))
+        .and(path(format!("/subjects/{}/versions/{}", subject, version)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 1,
+            "subject": subject,
+            "version": 1,
+            "schema": SCHEMA,
+            "references": references
+        })))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn reference_closure_lists_dependencies_first() {
+    let server = MockServer::start().await;
+
+    mount_subject_version(
+        &server,
+        "book-value",
+        "latest",
+        json!([{ "name": "com.example.Author", "subject": "author-value", "version": 1 }]),
+    )
+    .await;
+    mount_subject_version(&server, "author-value", "1", json!([])).await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let closure = client
+        .reference_closure("book-value", Version::Latest)
+        .await
+        .unwrap();
+
+    let subjects = closure
+        .iter()
+        .map(|s| s.subject.as_str())
+        .collect::<Vec<_>>();
+
+    assert_eq!(subjects, vec!["author-value", "book-value"]);
+}
+
+#[tokio::test]
+async fn reference_closure_detects_cycles() {
+    let server = MockServer::start().await;
+
+    mount_subject_version(
+        &server,
+        "book-value",
+        "1",
+        json!([{ "name": "com.example.Author", "subject": "author-value", "version": 1 }]),
+    )
+    .await;
+    mount_subject_version(
+        &server,
+        "author-value",
+        "1",
+        json!([{ "name": "com.example.Book", "subject": "book-value", "version": 1 }]),
+    )
+    .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let result = client
+        .reference_closure("book-value", Version::Number(1))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(SchemaRegistryError::ReferenceCycle { .. })
+    ));
+}
+
+#[tokio::test]
+async fn diff_against_snapshot_reports_added_subjects() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/config"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "compatibilityLevel": "BACKWARD" })),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!(["book-value", "author-value"])),
+        )
+        .mount(&server)
+        .await;
+
+    for subject in ["book-value", "author-value"] {
+        Mock::given(method("GET"))
+            .and(path(format!("/config/{}", subject)))
+            .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+                "error_code": 40408,
+                "message": "Subject does not have subject-level compatibility configured"
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path(format!("/subjects/{}/versions", subject)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([1])))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path(format!("/subjects/{}/versions/1", subject)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": 1,
+                "subject": subject,
+                "version": 1,
+                "schema": SCHEMA
+            })))
+            .mount(&server)
+            .await;
+    }
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let mut snapshot = client.export_all(false).await.unwrap();
+    snapshot.subjects.remove("author-value");
+
+    let diff = client.diff_against_snapshot(&snapshot).await.unwrap();
+
+    assert_eq!(diff.added, vec!["author-value"]);
+    assert!(diff.removed.is_empty());
+    assert!(diff.changed.is_empty());
+    assert!(diff.config_changed.is_empty());
+    assert!(!diff.global_config_changed);
+}
+
+#[tokio::test]
+async fn post_new_subject_version_requests_normalization() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/book-value/versions"))
+        .and(query_param("normalize", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 7 })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let id = client
+        .post_new_subject_version("book-value", &UnregisteredSchema::schema(SCHEMA), true)
+        .await
+        .unwrap();
+
+    assert_eq!(id, 7);
+}
+
+#[tokio::test]
+async fn frame_for_subject_version_prefixes_the_schema_id() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions/2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 258,
+            "subject": "book-value",
+            "version": 2,
+            "schema": SCHEMA
+        })))
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let framed = client
+        .frame_for_subject_version("book-value", Version::Number(2), b"payload")
+        .await
+        .unwrap();
+
+    assert_eq!(&framed[..5], &[0, 0, 0, 1, 2]);
+    assert_eq!(&framed[5..], b"payload");
+}
+
+#[tokio::test]
+async fn full_index_lists_every_subject_version_and_id() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!(["book-value", "author-value"])),
+        )
+        .mount(&server)
+        .await;
+
+    for (subject, versions) in [("book-value", vec![1, 2]), ("author-value", vec![1])] {
+        Mock::given(method("GET"))
+            .and(path(format!("/subjects/{}/versions", subject)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!(versions)))
+            .mount(&server)
+            .await;
+    }
+
+    for (subject, version, id) in [
+        ("book-value", 1, 10),
+        ("book-value", 2, 11),
+        ("author-value", 1, 20),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(format!("/subjects/{}/versions/{}", subject, version)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": id,
+                "subject": subject,
+                "version": version,
+                "schema": SCHEMA
+            })))
+            .mount(&server)
+            .await;
+    }
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let index = client.full_index().await.unwrap();
+
+    assert_eq!(
+        index,
+        vec![
+            ("author-value".to_owned(), 1, 20),
+            ("book-value".to_owned(), 1, 10),
+            ("book-value".to_owned(), 2, 11),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn soft_deleted_versions_are_listed_when_requested() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions"))
+        .and(query_param("deleted", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([1, 2, 3])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions"))
+        .and(query_param_is_missing("deleted"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([3])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let all = client
+        .get_subject_versions_with_deleted("book-value", true)
+        .await
+        .unwrap();
+    let live = client
+        .get_subject_versions_with_deleted("book-value", false)
+        .await
+        .unwrap();
+
+    assert_eq!(all, vec![1, 2, 3]);
+    assert_eq!(live, vec![3]);
+}
+
+#[tokio::test]
+async fn soft_deleted_version_is_fetched_when_requested() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/book-value/versions/1"))
+        .and(query_param("deleted", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "subject": "book-value",
+            "id": 1,
+            "version": 1,
+            "schema": SCHEMA
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let subject = client
+        .get_subject_version_opt("book-value", Version::Number(1), true)
+        .await
+        .unwrap();
+
+    assert_eq!(subject.version, 1);
+}
+
+#[tokio::test]
+async fn subjects_stream_yields_latest_version_of_each_subject() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subjects"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            "authors-value",
+            "books-value",
+            "orders-value"
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    for (subject, id) in [("authors-value", 1), ("orders-value", 3)] {
+        Mock::given(method("GET"))
+            .and(path(format!("/subjects/{}/versions/latest", subject)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "subject": subject,
+                "id": id,
+                "version": 1,
+                "schema": SCHEMA
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    Mock::given(method("GET"))
+        .and(path("/subjects/books-value/versions/latest"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error_code": 40401,
+            "message": "Subject 'books-value' not found."
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+
+    let results: Vec<_> = client.subjects_stream(false).collect().await;
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().subject, "authors-value");
+    assert!(results[1].as_ref().unwrap_err().is_subject_not_found());
+    assert_eq!(results[2].as_ref().unwrap().id, 3);
+}
+
+#[tokio::test]
+async fn register_subject_schema_full_returns_the_assigned_version() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/book-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 7,
+            "version": 3,
+            "subject": "book-value",
+            "schema": SCHEMA
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+    let schema = UnregisteredSchema::schema(SCHEMA);
+
+    let subject = client
+        .register_subject_schema_full("book-value", &schema, false)
+        .await
+        .unwrap();
+
+    assert_eq!(subject.id, 7);
+    assert_eq!(subject.version, 3);
+    assert_eq!(subject.subject, "book-value");
+}
+
+#[tokio::test]
+async fn register_subject_schema_full_looks_up_the_version_when_missing() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/book-value/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 7 })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/book-value"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "subject": "book-value",
+            "id": 7,
+            "version": 3,
+            "schema": SCHEMA
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+    let schema = UnregisteredSchema::schema(SCHEMA);
+
+    let subject = client
+        .register_subject_schema_full("book-value", &schema, false)
+        .await
+        .unwrap();
+
+    assert_eq!(subject.id, 7);
+    assert_eq!(subject.version, 3);
+}
+
+#[tokio::test]
+async fn imported_schema_is_registered_with_its_id_and_version() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/subjects/book-value/versions"))
+        .and(body_json(json!({
+            "schema": SCHEMA,
+            "schemaType": "AVRO",
+            "id": 42,
+            "version": 3
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 42 })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = SchemaRegistryClient::from_url(&server.uri()).unwrap();
+    let schema = UnregisteredSchema::schema(SCHEMA)
+        .with_id(42)
+        .with_version(3);
+
+    let id = client
+        .post_new_subject_version("book-value", &schema, false)
+        .await
+        .unwrap();
+
+    assert_eq!(id, 42);
+}